@@ -0,0 +1,43 @@
+import * as beet from "@metaplex-foundation/beet";
+
+// beet 0.0.8, which the generated code targets, only handles scalar enums.
+// Enums whose variants carry fields are serialized by borsh as a u8 variant
+// index followed by the fields of that variant.
+export type DataEnumVariant = { __kind: string };
+
+export function dataEnumBeet<T extends DataEnumVariant>(
+  variants: [T["__kind"], beet.BeetArgsStruct<any>][],
+  description: string
+): beet.FixableBeet<T, T> {
+  const variantBeet = (index: number): beet.FixedSizeBeet<T, T> => {
+    const [kind, fields] = variants[index];
+    return {
+      write(buf: Buffer, offset: number, value: T) {
+        beet.u8.write(buf, offset, index);
+        fields.write(buf, offset + 1, value);
+      },
+      read(buf: Buffer, offset: number): T {
+        return { __kind: kind, ...fields.read(buf, offset + 1) } as T;
+      },
+      byteSize: 1 + fields.byteSize,
+      description: `${description}.${kind}`,
+    };
+  };
+  return {
+    toFixedFromData(buf: Buffer, offset: number) {
+      const index = beet.u8.read(buf, offset);
+      if (index >= variants.length) {
+        throw new Error(`Unknown ${description} variant ${index}`);
+      }
+      return variantBeet(index);
+    },
+    toFixedFromValue(value: T) {
+      const index = variants.findIndex(([kind]) => kind === value.__kind);
+      if (index < 0) {
+        throw new Error(`Unknown ${description} variant ${value.__kind}`);
+      }
+      return variantBeet(index);
+    },
+    description,
+  };
+}
@@ -9,6 +9,20 @@ import * as web3 from "@solana/web3.js";
 import * as beet from "@metaplex-foundation/beet";
 import * as beetSolana from "@metaplex-foundation/beet-solana";
 import { MembershipModel, membershipModelBeet } from "../types/MembershipModel";
+import { CrankReward, crankRewardBeet } from "../types/CrankReward";
+import { UnstakedPolicy, unstakedPolicyBeet } from "../types/UnstakedPolicy";
+import {
+  ResignationPolicy,
+  resignationPolicyBeet,
+} from "../types/ResignationPolicy";
+import {
+  DistributionWindow,
+  distributionWindowBeet,
+} from "../types/DistributionWindow";
+import {
+  DistributionWindowScope,
+  distributionWindowScopeBeet,
+} from "../types/DistributionWindowScope";
 
 /**
  * Arguments used to create {@link Fanout}
@@ -29,6 +43,41 @@ export type FanoutArgs = {
   membershipModel: MembershipModel;
   membershipMint: beet.COption<web3.PublicKey>;
   totalStakedShares: beet.COption<beet.bignum>;
+  unwrapNativeMint: boolean;
+  feeBasisPoints: number;
+  feeRecipient: beet.COption<web3.PublicKey>;
+  totalFees: beet.bignum;
+  crankReward: beet.COption<CrankReward>;
+  totalCrankRewards: beet.bignum;
+  membershipCollection: beet.COption<web3.PublicKey>;
+  sharesPerNft: beet.bignum;
+  sharesRoot: beet.COption<number[] /* size: 32 */>;
+  reserve: beet.COption<web3.PublicKey>;
+  reserveUnclaimed: beet.bignum;
+  unstakedPolicy: UnstakedPolicy;
+  treasuryUnclaimed: beet.bignum;
+  membershipSupply: beet.bignum;
+  supplyCheckpoint: beet.bignum;
+  weightedMintSupply: beet.bignum;
+  memberTransfersEnabled: boolean;
+  resignationPolicy: ResignationPolicy;
+  retiredShares: beet.bignum;
+  sharesReleased: boolean;
+  shareScheduleAt: beet.COption<beet.bignum>;
+  shareEpoch: beet.bignum;
+  shareSwitchInflow: beet.bignum;
+  scheduledShareDelta: beet.bignum;
+  pendingShareChanges: beet.bignum;
+  base: beet.COption<web3.PublicKey>;
+  version: number;
+  minDistribution: beet.bignum;
+  distributionWindow: beet.COption<DistributionWindow>;
+  distributionWindowScope: DistributionWindowScope;
+  lastDistributionWindow: beet.COption<beet.bignum>;
+  mintsWithReserve: number;
+  totalMints: beet.bignum;
+  shareSwitchChanges: beet.bignum;
+  expiringShares: beet.bignum;
 };
 
 const fanoutDiscriminator = [164, 101, 210, 92, 222, 14, 75, 156];
@@ -53,7 +102,42 @@ export class Fanout implements FanoutArgs {
     readonly totalAvailableShares: beet.bignum,
     readonly membershipModel: MembershipModel,
     readonly membershipMint: beet.COption<web3.PublicKey>,
-    readonly totalStakedShares: beet.COption<beet.bignum>
+    readonly totalStakedShares: beet.COption<beet.bignum>,
+    readonly unwrapNativeMint: boolean,
+    readonly feeBasisPoints: number,
+    readonly feeRecipient: beet.COption<web3.PublicKey>,
+    readonly totalFees: beet.bignum,
+    readonly crankReward: beet.COption<CrankReward>,
+    readonly totalCrankRewards: beet.bignum,
+    readonly membershipCollection: beet.COption<web3.PublicKey>,
+    readonly sharesPerNft: beet.bignum,
+    readonly sharesRoot: beet.COption<number[] /* size: 32 */>,
+    readonly reserve: beet.COption<web3.PublicKey>,
+    readonly reserveUnclaimed: beet.bignum,
+    readonly unstakedPolicy: UnstakedPolicy,
+    readonly treasuryUnclaimed: beet.bignum,
+    readonly membershipSupply: beet.bignum,
+    readonly supplyCheckpoint: beet.bignum,
+    readonly weightedMintSupply: beet.bignum,
+    readonly memberTransfersEnabled: boolean,
+    readonly resignationPolicy: ResignationPolicy,
+    readonly retiredShares: beet.bignum,
+    readonly sharesReleased: boolean,
+    readonly shareScheduleAt: beet.COption<beet.bignum>,
+    readonly shareEpoch: beet.bignum,
+    readonly shareSwitchInflow: beet.bignum,
+    readonly scheduledShareDelta: beet.bignum,
+    readonly pendingShareChanges: beet.bignum,
+    readonly base: beet.COption<web3.PublicKey>,
+    readonly version: number,
+    readonly minDistribution: beet.bignum,
+    readonly distributionWindow: beet.COption<DistributionWindow>,
+    readonly distributionWindowScope: DistributionWindowScope,
+    readonly lastDistributionWindow: beet.COption<beet.bignum>,
+    readonly mintsWithReserve: number,
+    readonly totalMints: beet.bignum,
+    readonly shareSwitchChanges: beet.bignum,
+    readonly expiringShares: beet.bignum
   ) {}
 
   /**
@@ -73,7 +157,42 @@ export class Fanout implements FanoutArgs {
       args.totalAvailableShares,
       args.membershipModel,
       args.membershipMint,
-      args.totalStakedShares
+      args.totalStakedShares,
+      args.unwrapNativeMint,
+      args.feeBasisPoints,
+      args.feeRecipient,
+      args.totalFees,
+      args.crankReward,
+      args.totalCrankRewards,
+      args.membershipCollection,
+      args.sharesPerNft,
+      args.sharesRoot,
+      args.reserve,
+      args.reserveUnclaimed,
+      args.unstakedPolicy,
+      args.treasuryUnclaimed,
+      args.membershipSupply,
+      args.supplyCheckpoint,
+      args.weightedMintSupply,
+      args.memberTransfersEnabled,
+      args.resignationPolicy,
+      args.retiredShares,
+      args.sharesReleased,
+      args.shareScheduleAt,
+      args.shareEpoch,
+      args.shareSwitchInflow,
+      args.scheduledShareDelta,
+      args.pendingShareChanges,
+      args.base,
+      args.version,
+      args.minDistribution,
+      args.distributionWindow,
+      args.distributionWindowScope,
+      args.lastDistributionWindow,
+      args.mintsWithReserve,
+      args.totalMints,
+      args.shareSwitchChanges,
+      args.expiringShares
     );
   }
 
@@ -178,6 +297,43 @@ export class Fanout implements FanoutArgs {
         "MembershipModel." + MembershipModel[this.membershipModel],
       membershipMint: this.membershipMint,
       totalStakedShares: this.totalStakedShares,
+      unwrapNativeMint: this.unwrapNativeMint,
+      feeBasisPoints: this.feeBasisPoints,
+      feeRecipient: this.feeRecipient,
+      totalFees: this.totalFees,
+      crankReward: this.crankReward,
+      totalCrankRewards: this.totalCrankRewards,
+      membershipCollection: this.membershipCollection,
+      sharesPerNft: this.sharesPerNft,
+      sharesRoot: this.sharesRoot,
+      reserve: this.reserve,
+      reserveUnclaimed: this.reserveUnclaimed,
+      unstakedPolicy: "UnstakedPolicy." + UnstakedPolicy[this.unstakedPolicy],
+      treasuryUnclaimed: this.treasuryUnclaimed,
+      membershipSupply: this.membershipSupply,
+      supplyCheckpoint: this.supplyCheckpoint,
+      weightedMintSupply: this.weightedMintSupply,
+      memberTransfersEnabled: this.memberTransfersEnabled,
+      resignationPolicy:
+        "ResignationPolicy." + ResignationPolicy[this.resignationPolicy],
+      retiredShares: this.retiredShares,
+      sharesReleased: this.sharesReleased,
+      shareScheduleAt: this.shareScheduleAt,
+      shareEpoch: this.shareEpoch,
+      shareSwitchInflow: this.shareSwitchInflow,
+      scheduledShareDelta: this.scheduledShareDelta,
+      pendingShareChanges: this.pendingShareChanges,
+      base: this.base,
+      version: this.version,
+      minDistribution: this.minDistribution,
+      distributionWindow: this.distributionWindow,
+      distributionWindowScope:
+        "DistributionWindowScope." + DistributionWindowScope[this.distributionWindowScope],
+      lastDistributionWindow: this.lastDistributionWindow,
+      mintsWithReserve: this.mintsWithReserve,
+      totalMints: this.totalMints,
+      shareSwitchChanges: this.shareSwitchChanges,
+      expiringShares: this.expiringShares,
     };
   }
 }
@@ -207,6 +363,41 @@ export const fanoutBeet = new beet.FixableBeetStruct<
     ["membershipModel", membershipModelBeet],
     ["membershipMint", beet.coption(beetSolana.publicKey)],
     ["totalStakedShares", beet.coption(beet.u64)],
+    ["unwrapNativeMint", beet.bool],
+    ["feeBasisPoints", beet.u16],
+    ["feeRecipient", beet.coption(beetSolana.publicKey)],
+    ["totalFees", beet.u64],
+    ["crankReward", beet.coption(crankRewardBeet)],
+    ["totalCrankRewards", beet.u64],
+    ["membershipCollection", beet.coption(beetSolana.publicKey)],
+    ["sharesPerNft", beet.u64],
+    ["sharesRoot", beet.coption(beet.uniformFixedSizeArray(beet.u8, 32))],
+    ["reserve", beet.coption(beetSolana.publicKey)],
+    ["reserveUnclaimed", beet.u64],
+    ["unstakedPolicy", unstakedPolicyBeet],
+    ["treasuryUnclaimed", beet.u64],
+    ["membershipSupply", beet.u64],
+    ["supplyCheckpoint", beet.u64],
+    ["weightedMintSupply", beet.u64],
+    ["memberTransfersEnabled", beet.bool],
+    ["resignationPolicy", resignationPolicyBeet],
+    ["retiredShares", beet.u64],
+    ["sharesReleased", beet.bool],
+    ["shareScheduleAt", beet.coption(beet.i64)],
+    ["shareEpoch", beet.u64],
+    ["shareSwitchInflow", beet.u64],
+    ["scheduledShareDelta", beet.i64],
+    ["pendingShareChanges", beet.u64],
+    ["base", beet.coption(beetSolana.publicKey)],
+    ["version", beet.u8],
+    ["minDistribution", beet.u64],
+    ["distributionWindow", beet.coption(distributionWindowBeet)],
+    ["distributionWindowScope", distributionWindowScopeBeet],
+    ["lastDistributionWindow", beet.coption(beet.u64)],
+    ["mintsWithReserve", beet.u32],
+    ["totalMints", beet.u64],
+    ["shareSwitchChanges", beet.u64],
+    ["expiringShares", beet.u64],
   ],
   Fanout.fromArgs,
   "Fanout"
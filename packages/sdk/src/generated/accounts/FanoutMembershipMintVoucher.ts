@@ -19,6 +19,12 @@ export type FanoutMembershipMintVoucherArgs = {
   fanoutMint: web3.PublicKey;
   lastInflow: beet.bignum;
   bumpSeed: number;
+  totalInflow: beet.bignum;
+  totalInflowMigrated: boolean;
+  shareEpoch: beet.bignum;
+  shares: beet.bignum;
+  version: number;
+  lastDistributionWindow: beet.COption<beet.bignum>;
 };
 
 const fanoutMembershipMintVoucherDiscriminator = [
@@ -38,7 +44,13 @@ export class FanoutMembershipMintVoucher
     readonly fanout: web3.PublicKey,
     readonly fanoutMint: web3.PublicKey,
     readonly lastInflow: beet.bignum,
-    readonly bumpSeed: number
+    readonly bumpSeed: number,
+    readonly totalInflow: beet.bignum,
+    readonly totalInflowMigrated: boolean,
+    readonly shareEpoch: beet.bignum,
+    readonly shares: beet.bignum,
+    readonly version: number,
+    readonly lastDistributionWindow: beet.COption<beet.bignum>
   ) {}
 
   /**
@@ -49,7 +61,13 @@ export class FanoutMembershipMintVoucher
       args.fanout,
       args.fanoutMint,
       args.lastInflow,
-      args.bumpSeed
+      args.bumpSeed,
+      args.totalInflow,
+      args.totalInflowMigrated,
+      args.shareEpoch,
+      args.shares,
+      args.version,
+      args.lastDistributionWindow
     );
   }
 
@@ -107,36 +125,38 @@ export class FanoutMembershipMintVoucher
 
   /**
    * Returns the byteSize of a {@link Buffer} holding the serialized data of
-   * {@link FanoutMembershipMintVoucher}
+   * {@link FanoutMembershipMintVoucher} for the provided args.
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    */
-  static get byteSize() {
-    return fanoutMembershipMintVoucherBeet.byteSize;
+  static byteSize(args: FanoutMembershipMintVoucherArgs) {
+    const instance = FanoutMembershipMintVoucher.fromArgs(args);
+    return fanoutMembershipMintVoucherBeet.toFixedFromValue({
+      accountDiscriminator: fanoutMembershipMintVoucherDiscriminator,
+      ...instance,
+    }).byteSize;
   }
 
   /**
    * Fetches the minimum balance needed to exempt an account holding
    * {@link FanoutMembershipMintVoucher} data from rent
    *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    * @param connection used to retrieve the rent exemption information
    */
   static async getMinimumBalanceForRentExemption(
+    args: FanoutMembershipMintVoucherArgs,
     connection: web3.Connection,
     commitment?: web3.Commitment
   ): Promise<number> {
     return connection.getMinimumBalanceForRentExemption(
-      FanoutMembershipMintVoucher.byteSize,
+      FanoutMembershipMintVoucher.byteSize(args),
       commitment
     );
   }
 
-  /**
-   * Determines if the provided {@link Buffer} has the correct byte size to
-   * hold {@link FanoutMembershipMintVoucher} data.
-   */
-  static hasCorrectByteSize(buf: Buffer, offset = 0) {
-    return buf.byteLength - offset === FanoutMembershipMintVoucher.byteSize;
-  }
-
   /**
    * Returns a readable version of {@link FanoutMembershipMintVoucher} properties
    * and can be used to convert to JSON and/or logging
@@ -147,6 +167,12 @@ export class FanoutMembershipMintVoucher
       fanoutMint: this.fanoutMint.toBase58(),
       lastInflow: this.lastInflow,
       bumpSeed: this.bumpSeed,
+      totalInflow: this.totalInflow,
+      totalInflowMigrated: this.totalInflowMigrated,
+      shareEpoch: this.shareEpoch,
+      shares: this.shares,
+      version: this.version,
+      lastDistributionWindow: this.lastDistributionWindow,
     };
   }
 }
@@ -155,7 +181,7 @@ export class FanoutMembershipMintVoucher
  * @category Accounts
  * @category generated
  */
-export const fanoutMembershipMintVoucherBeet = new beet.BeetStruct<
+export const fanoutMembershipMintVoucherBeet = new beet.FixableBeetStruct<
   FanoutMembershipMintVoucher,
   FanoutMembershipMintVoucherArgs & {
     accountDiscriminator: number[] /* size: 8 */;
@@ -167,6 +193,12 @@ export const fanoutMembershipMintVoucherBeet = new beet.BeetStruct<
     ["fanoutMint", beetSolana.publicKey],
     ["lastInflow", beet.u64],
     ["bumpSeed", beet.u8],
+    ["totalInflow", beet.u64],
+    ["totalInflowMigrated", beet.bool],
+    ["shareEpoch", beet.u64],
+    ["shares", beet.u64],
+    ["version", beet.u8],
+    ["lastDistributionWindow", beet.coption(beet.u64)],
   ],
   FanoutMembershipMintVoucher.fromArgs,
   "FanoutMembershipMintVoucher"
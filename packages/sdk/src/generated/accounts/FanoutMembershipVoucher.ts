@@ -21,6 +21,15 @@ export type FanoutMembershipVoucherArgs = {
   bumpSeed: number;
   membershipKey: web3.PublicKey;
   shares: beet.bignum;
+  payoutDestination: beet.COption<web3.PublicKey>;
+  rentPayer: beet.COption<web3.PublicKey>;
+  expiresAt: beet.COption<beet.bignum>;
+  scheduledShares: beet.COption<beet.bignum>;
+  shareEpoch: beet.bignum;
+  previousShares: beet.bignum;
+  version: number;
+  label: beet.COption<string>;
+  lastDistributionWindow: beet.COption<beet.bignum>;
 };
 
 const fanoutMembershipVoucherDiscriminator = [
@@ -40,7 +49,16 @@ export class FanoutMembershipVoucher implements FanoutMembershipVoucherArgs {
     readonly lastInflow: beet.bignum,
     readonly bumpSeed: number,
     readonly membershipKey: web3.PublicKey,
-    readonly shares: beet.bignum
+    readonly shares: beet.bignum,
+    readonly payoutDestination: beet.COption<web3.PublicKey>,
+    readonly rentPayer: beet.COption<web3.PublicKey>,
+    readonly expiresAt: beet.COption<beet.bignum>,
+    readonly scheduledShares: beet.COption<beet.bignum>,
+    readonly shareEpoch: beet.bignum,
+    readonly previousShares: beet.bignum,
+    readonly version: number,
+    readonly label: beet.COption<string>,
+    readonly lastDistributionWindow: beet.COption<beet.bignum>
   ) {}
 
   /**
@@ -53,7 +71,16 @@ export class FanoutMembershipVoucher implements FanoutMembershipVoucherArgs {
       args.lastInflow,
       args.bumpSeed,
       args.membershipKey,
-      args.shares
+      args.shares,
+      args.payoutDestination,
+      args.rentPayer,
+      args.expiresAt,
+      args.scheduledShares,
+      args.shareEpoch,
+      args.previousShares,
+      args.version,
+      args.label,
+      args.lastDistributionWindow
     );
   }
 
@@ -111,36 +138,38 @@ export class FanoutMembershipVoucher implements FanoutMembershipVoucherArgs {
 
   /**
    * Returns the byteSize of a {@link Buffer} holding the serialized data of
-   * {@link FanoutMembershipVoucher}
+   * {@link FanoutMembershipVoucher} for the provided args.
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    */
-  static get byteSize() {
-    return fanoutMembershipVoucherBeet.byteSize;
+  static byteSize(args: FanoutMembershipVoucherArgs) {
+    const instance = FanoutMembershipVoucher.fromArgs(args);
+    return fanoutMembershipVoucherBeet.toFixedFromValue({
+      accountDiscriminator: fanoutMembershipVoucherDiscriminator,
+      ...instance,
+    }).byteSize;
   }
 
   /**
    * Fetches the minimum balance needed to exempt an account holding
    * {@link FanoutMembershipVoucher} data from rent
    *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    * @param connection used to retrieve the rent exemption information
    */
   static async getMinimumBalanceForRentExemption(
+    args: FanoutMembershipVoucherArgs,
     connection: web3.Connection,
     commitment?: web3.Commitment
   ): Promise<number> {
     return connection.getMinimumBalanceForRentExemption(
-      FanoutMembershipVoucher.byteSize,
+      FanoutMembershipVoucher.byteSize(args),
       commitment
     );
   }
 
-  /**
-   * Determines if the provided {@link Buffer} has the correct byte size to
-   * hold {@link FanoutMembershipVoucher} data.
-   */
-  static hasCorrectByteSize(buf: Buffer, offset = 0) {
-    return buf.byteLength - offset === FanoutMembershipVoucher.byteSize;
-  }
-
   /**
    * Returns a readable version of {@link FanoutMembershipVoucher} properties
    * and can be used to convert to JSON and/or logging
@@ -153,6 +182,15 @@ export class FanoutMembershipVoucher implements FanoutMembershipVoucherArgs {
       bumpSeed: this.bumpSeed,
       membershipKey: this.membershipKey.toBase58(),
       shares: this.shares,
+      payoutDestination: this.payoutDestination,
+      rentPayer: this.rentPayer,
+      expiresAt: this.expiresAt,
+      scheduledShares: this.scheduledShares,
+      shareEpoch: this.shareEpoch,
+      previousShares: this.previousShares,
+      version: this.version,
+      label: this.label,
+      lastDistributionWindow: this.lastDistributionWindow,
     };
   }
 }
@@ -161,7 +199,7 @@ export class FanoutMembershipVoucher implements FanoutMembershipVoucherArgs {
  * @category Accounts
  * @category generated
  */
-export const fanoutMembershipVoucherBeet = new beet.BeetStruct<
+export const fanoutMembershipVoucherBeet = new beet.FixableBeetStruct<
   FanoutMembershipVoucher,
   FanoutMembershipVoucherArgs & {
     accountDiscriminator: number[] /* size: 8 */;
@@ -175,6 +213,15 @@ export const fanoutMembershipVoucherBeet = new beet.BeetStruct<
     ["bumpSeed", beet.u8],
     ["membershipKey", beetSolana.publicKey],
     ["shares", beet.u64],
+    ["payoutDestination", beet.coption(beetSolana.publicKey)],
+    ["rentPayer", beet.coption(beetSolana.publicKey)],
+    ["expiresAt", beet.coption(beet.i64)],
+    ["scheduledShares", beet.coption(beet.u64)],
+    ["shareEpoch", beet.u64],
+    ["previousShares", beet.u64],
+    ["version", beet.u8],
+    ["label", beet.coption(beet.utf8String)],
+    ["lastDistributionWindow", beet.coption(beet.u64)],
   ],
   FanoutMembershipVoucher.fromArgs,
   "FanoutMembershipVoucher"
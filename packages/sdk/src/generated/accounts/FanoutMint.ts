@@ -21,6 +21,19 @@ export type FanoutMintArgs = {
   totalInflow: beet.bignum;
   lastSnapshotAmount: beet.bignum;
   bumpSeed: number;
+  totalFees: beet.bignum;
+  reserveUnclaimed: beet.bignum;
+  treasuryUnclaimed: beet.bignum;
+  supplyCheckpoint: beet.bignum;
+  shareEpoch: beet.bignum;
+  shareSwitchInflow: beet.bignum;
+  totalShares: beet.COption<beet.bignum>;
+  totalAvailableShares: beet.bignum;
+  version: number;
+  minDistribution: beet.bignum;
+  counted: boolean;
+  pendingShareChanges: number;
+  lastDistributionWindow: beet.COption<beet.bignum>;
 };
 
 const fanoutMintDiscriminator = [50, 164, 42, 108, 90, 201, 250, 216];
@@ -38,7 +51,20 @@ export class FanoutMint implements FanoutMintArgs {
     readonly tokenAccount: web3.PublicKey,
     readonly totalInflow: beet.bignum,
     readonly lastSnapshotAmount: beet.bignum,
-    readonly bumpSeed: number
+    readonly bumpSeed: number,
+    readonly totalFees: beet.bignum,
+    readonly reserveUnclaimed: beet.bignum,
+    readonly treasuryUnclaimed: beet.bignum,
+    readonly supplyCheckpoint: beet.bignum,
+    readonly shareEpoch: beet.bignum,
+    readonly shareSwitchInflow: beet.bignum,
+    readonly totalShares: beet.COption<beet.bignum>,
+    readonly totalAvailableShares: beet.bignum,
+    readonly version: number,
+    readonly minDistribution: beet.bignum,
+    readonly counted: boolean,
+    readonly pendingShareChanges: number,
+    readonly lastDistributionWindow: beet.COption<beet.bignum>
   ) {}
 
   /**
@@ -51,7 +77,20 @@ export class FanoutMint implements FanoutMintArgs {
       args.tokenAccount,
       args.totalInflow,
       args.lastSnapshotAmount,
-      args.bumpSeed
+      args.bumpSeed,
+      args.totalFees,
+      args.reserveUnclaimed,
+      args.treasuryUnclaimed,
+      args.supplyCheckpoint,
+      args.shareEpoch,
+      args.shareSwitchInflow,
+      args.totalShares,
+      args.totalAvailableShares,
+      args.version,
+      args.minDistribution,
+      args.counted,
+      args.pendingShareChanges,
+      args.lastDistributionWindow
     );
   }
 
@@ -104,36 +143,38 @@ export class FanoutMint implements FanoutMintArgs {
 
   /**
    * Returns the byteSize of a {@link Buffer} holding the serialized data of
-   * {@link FanoutMint}
+   * {@link FanoutMint} for the provided args.
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    */
-  static get byteSize() {
-    return fanoutMintBeet.byteSize;
+  static byteSize(args: FanoutMintArgs) {
+    const instance = FanoutMint.fromArgs(args);
+    return fanoutMintBeet.toFixedFromValue({
+      accountDiscriminator: fanoutMintDiscriminator,
+      ...instance,
+    }).byteSize;
   }
 
   /**
    * Fetches the minimum balance needed to exempt an account holding
    * {@link FanoutMint} data from rent
    *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    * @param connection used to retrieve the rent exemption information
    */
   static async getMinimumBalanceForRentExemption(
+    args: FanoutMintArgs,
     connection: web3.Connection,
     commitment?: web3.Commitment
   ): Promise<number> {
     return connection.getMinimumBalanceForRentExemption(
-      FanoutMint.byteSize,
+      FanoutMint.byteSize(args),
       commitment
     );
   }
 
-  /**
-   * Determines if the provided {@link Buffer} has the correct byte size to
-   * hold {@link FanoutMint} data.
-   */
-  static hasCorrectByteSize(buf: Buffer, offset = 0) {
-    return buf.byteLength - offset === FanoutMint.byteSize;
-  }
-
   /**
    * Returns a readable version of {@link FanoutMint} properties
    * and can be used to convert to JSON and/or logging
@@ -146,6 +187,19 @@ export class FanoutMint implements FanoutMintArgs {
       totalInflow: this.totalInflow,
       lastSnapshotAmount: this.lastSnapshotAmount,
       bumpSeed: this.bumpSeed,
+      totalFees: this.totalFees,
+      reserveUnclaimed: this.reserveUnclaimed,
+      treasuryUnclaimed: this.treasuryUnclaimed,
+      supplyCheckpoint: this.supplyCheckpoint,
+      shareEpoch: this.shareEpoch,
+      shareSwitchInflow: this.shareSwitchInflow,
+      totalShares: this.totalShares,
+      totalAvailableShares: this.totalAvailableShares,
+      version: this.version,
+      minDistribution: this.minDistribution,
+      counted: this.counted,
+      pendingShareChanges: this.pendingShareChanges,
+      lastDistributionWindow: this.lastDistributionWindow,
     };
   }
 }
@@ -154,7 +208,7 @@ export class FanoutMint implements FanoutMintArgs {
  * @category Accounts
  * @category generated
  */
-export const fanoutMintBeet = new beet.BeetStruct<
+export const fanoutMintBeet = new beet.FixableBeetStruct<
   FanoutMint,
   FanoutMintArgs & {
     accountDiscriminator: number[] /* size: 8 */;
@@ -168,6 +222,19 @@ export const fanoutMintBeet = new beet.BeetStruct<
     ["totalInflow", beet.u64],
     ["lastSnapshotAmount", beet.u64],
     ["bumpSeed", beet.u8],
+    ["totalFees", beet.u64],
+    ["reserveUnclaimed", beet.u64],
+    ["treasuryUnclaimed", beet.u64],
+    ["supplyCheckpoint", beet.u64],
+    ["shareEpoch", beet.u64],
+    ["shareSwitchInflow", beet.u64],
+    ["totalShares", beet.coption(beet.u64)],
+    ["totalAvailableShares", beet.u64],
+    ["version", beet.u8],
+    ["minDistribution", beet.u64],
+    ["counted", beet.bool],
+    ["pendingShareChanges", beet.u32],
+    ["lastDistributionWindow", beet.coption(beet.u64)],
   ],
   FanoutMint.fromArgs,
   "FanoutMint"
@@ -1,4 +1,7 @@
 export * from "./Fanout";
+export * from "./FanoutMembershipMint";
 export * from "./FanoutMembershipMintVoucher";
+export * from "./FanoutMembershipStake";
 export * from "./FanoutMembershipVoucher";
+export * from "./FanoutMetadata";
 export * from "./FanoutMint";
@@ -609,6 +609,1077 @@ createErrorFromNameLookup.set(
   () => new InvalidCloseAccountDestinationError()
 );
 
+/**
+ * NativeMintUnwrapped: 'Wrapped SOL is unwrapped into the native holding account on this fanout'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NativeMintUnwrappedError extends Error {
+  readonly code: number = 0x1789;
+  readonly name: string = "NativeMintUnwrapped";
+  constructor() {
+    super(
+      "Wrapped SOL is unwrapped into the native holding account on this fanout"
+    );
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, NativeMintUnwrappedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1789, () => new NativeMintUnwrappedError());
+createErrorFromNameLookup.set(
+  "NativeMintUnwrapped",
+  () => new NativeMintUnwrappedError()
+);
+
+/**
+ * NativeMintUnwrapDisabled: 'Wrapped SOL unwrapping is not enabled on this fanout'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NativeMintUnwrapDisabledError extends Error {
+  readonly code: number = 0x178a;
+  readonly name: string = "NativeMintUnwrapDisabled";
+  constructor() {
+    super("Wrapped SOL unwrapping is not enabled on this fanout");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, NativeMintUnwrapDisabledError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x178a,
+  () => new NativeMintUnwrapDisabledError()
+);
+createErrorFromNameLookup.set(
+  "NativeMintUnwrapDisabled",
+  () => new NativeMintUnwrapDisabledError()
+);
+
+/**
+ * InvalidFee: 'Fee basis points must be at most 10000'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidFeeError extends Error {
+  readonly code: number = 0x178b;
+  readonly name: string = "InvalidFee";
+  constructor() {
+    super("Fee basis points must be at most 10000");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, InvalidFeeError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178b, () => new InvalidFeeError());
+createErrorFromNameLookup.set("InvalidFee", () => new InvalidFeeError());
+
+/**
+ * InvalidFeeRecipient: 'Fee recipient does not match the config'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidFeeRecipientError extends Error {
+  readonly code: number = 0x178c;
+  readonly name: string = "InvalidFeeRecipient";
+  constructor() {
+    super("Fee recipient does not match the config");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, InvalidFeeRecipientError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178c, () => new InvalidFeeRecipientError());
+createErrorFromNameLookup.set(
+  "InvalidFeeRecipient",
+  () => new InvalidFeeRecipientError()
+);
+
+/**
+ * InvalidCrankReward: 'Crank reward basis points must be at most 10000'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidCrankRewardError extends Error {
+  readonly code: number = 0x178d;
+  readonly name: string = "InvalidCrankReward";
+  constructor() {
+    super("Crank reward basis points must be at most 10000");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, InvalidCrankRewardError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178d, () => new InvalidCrankRewardError());
+createErrorFromNameLookup.set(
+  "InvalidCrankReward",
+  () => new InvalidCrankRewardError()
+);
+
+/**
+ * AlreadyMigrated: 'This mint membership voucher has already been migrated'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AlreadyMigratedError extends Error {
+  readonly code: number = 0x178e;
+  readonly name: string = "AlreadyMigrated";
+  constructor() {
+    super("This mint membership voucher has already been migrated");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, AlreadyMigratedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178e, () => new AlreadyMigratedError());
+createErrorFromNameLookup.set(
+  "AlreadyMigrated",
+  () => new AlreadyMigratedError()
+);
+
+/**
+ * InvalidCollection: 'The NFT is not a verified member of the fanout collection'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidCollectionError extends Error {
+  readonly code: number = 0x178f;
+  readonly name: string = "InvalidCollection";
+  constructor() {
+    super("The NFT is not a verified member of the fanout collection");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, InvalidCollectionError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178f, () => new InvalidCollectionError());
+createErrorFromNameLookup.set(
+  "InvalidCollection",
+  () => new InvalidCollectionError()
+);
+
+/**
+ * CollectionRequired: 'This fanout is not bound to a collection'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class CollectionRequiredError extends Error {
+  readonly code: number = 0x1790;
+  readonly name: string = "CollectionRequired";
+  constructor() {
+    super("This fanout is not bound to a collection");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, CollectionRequiredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1790, () => new CollectionRequiredError());
+createErrorFromNameLookup.set(
+  "CollectionRequired",
+  () => new CollectionRequiredError()
+);
+
+/**
+ * InvalidProof: 'The merkle proof does not match the committed root'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidProofError extends Error {
+  readonly code: number = 0x1791;
+  readonly name: string = "InvalidProof";
+  constructor() {
+    super("The merkle proof does not match the committed root");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, InvalidProofError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1791, () => new InvalidProofError());
+createErrorFromNameLookup.set("InvalidProof", () => new InvalidProofError());
+
+/**
+ * SharesRootRequired: 'This fanout has no committed shares root'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SharesRootRequiredError extends Error {
+  readonly code: number = 0x1792;
+  readonly name: string = "SharesRootRequired";
+  constructor() {
+    super("This fanout has no committed shares root");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, SharesRootRequiredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1792, () => new SharesRootRequiredError());
+createErrorFromNameLookup.set(
+  "SharesRootRequired",
+  () => new SharesRootRequiredError()
+);
+
+/**
+ * ReserveNotSupported: 'Reserve is not supported on this membership model'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ReserveNotSupportedError extends Error {
+  readonly code: number = 0x1793;
+  readonly name: string = "ReserveNotSupported";
+  constructor() {
+    super("Reserve is not supported on this membership model");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, ReserveNotSupportedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1793, () => new ReserveNotSupportedError());
+createErrorFromNameLookup.set(
+  "ReserveNotSupported",
+  () => new ReserveNotSupportedError()
+);
+
+/**
+ * InvalidReserve: 'Reserve account does not match the config'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidReserveError extends Error {
+  readonly code: number = 0x1794;
+  readonly name: string = "InvalidReserve";
+  constructor() {
+    super("Reserve account does not match the config");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, InvalidReserveError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1794, () => new InvalidReserveError());
+createErrorFromNameLookup.set(
+  "InvalidReserve",
+  () => new InvalidReserveError()
+);
+
+/**
+ * ReserveHasUnclaimedInflow: 'The reserve must claim its native inflow before it is changed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ReserveHasUnclaimedInflowError extends Error {
+  readonly code: number = 0x1795;
+  readonly name: string = "ReserveHasUnclaimedInflow";
+  constructor() {
+    super("The reserve must claim its native inflow before it is changed");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, ReserveHasUnclaimedInflowError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x1795,
+  () => new ReserveHasUnclaimedInflowError()
+);
+createErrorFromNameLookup.set(
+  "ReserveHasUnclaimedInflow",
+  () => new ReserveHasUnclaimedInflowError()
+);
+
+/**
+ * InvalidWeight: 'Membership mint weight must be greater than zero'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidWeightError extends Error {
+  readonly code: number = 0x1796;
+  readonly name: string = "InvalidWeight";
+  constructor() {
+    super("Membership mint weight must be greater than zero");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, InvalidWeightError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1796, () => new InvalidWeightError());
+createErrorFromNameLookup.set("InvalidWeight", () => new InvalidWeightError());
+
+/**
+ * InvalidMembershipMint: 'The primary membership mint cannot be registered as a weighted mint'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidMembershipMintError extends Error {
+  readonly code: number = 0x1797;
+  readonly name: string = "InvalidMembershipMint";
+  constructor() {
+    super(
+      "The primary membership mint cannot be registered as a weighted mint"
+    );
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, InvalidMembershipMintError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1797, () => new InvalidMembershipMintError());
+createErrorFromNameLookup.set(
+  "InvalidMembershipMint",
+  () => new InvalidMembershipMintError()
+);
+
+/**
+ * WeightedStakeRemaining: 'Weighted membership mint stakes must be unstaked first'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class WeightedStakeRemainingError extends Error {
+  readonly code: number = 0x1798;
+  readonly name: string = "WeightedStakeRemaining";
+  constructor() {
+    super("Weighted membership mint stakes must be unstaked first");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, WeightedStakeRemainingError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1798, () => new WeightedStakeRemainingError());
+createErrorFromNameLookup.set(
+  "WeightedStakeRemaining",
+  () => new WeightedStakeRemainingError()
+);
+
+/**
+ * InvalidPayoutDestination: 'Payout destination does not match the membership voucher'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidPayoutDestinationError extends Error {
+  readonly code: number = 0x1799;
+  readonly name: string = "InvalidPayoutDestination";
+  constructor() {
+    super("Payout destination does not match the membership voucher");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, InvalidPayoutDestinationError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x1799,
+  () => new InvalidPayoutDestinationError()
+);
+createErrorFromNameLookup.set(
+  "InvalidPayoutDestination",
+  () => new InvalidPayoutDestinationError()
+);
+
+/**
+ * InvalidRotationSigner: 'Only the member or the fanout authority can rotate a member key'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidRotationSignerError extends Error {
+  readonly code: number = 0x179a;
+  readonly name: string = "InvalidRotationSigner";
+  constructor() {
+    super("Only the member or the fanout authority can rotate a member key");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, InvalidRotationSignerError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179a, () => new InvalidRotationSignerError());
+createErrorFromNameLookup.set(
+  "InvalidRotationSigner",
+  () => new InvalidRotationSignerError()
+);
+
+/**
+ * MemberTransfersDisabled: 'Members cannot transfer shares on this fanout'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MemberTransfersDisabledError extends Error {
+  readonly code: number = 0x179b;
+  readonly name: string = "MemberTransfersDisabled";
+  constructor() {
+    super("Members cannot transfer shares on this fanout");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, MemberTransfersDisabledError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179b, () => new MemberTransfersDisabledError());
+createErrorFromNameLookup.set(
+  "MemberTransfersDisabled",
+  () => new MemberTransfersDisabledError()
+);
+
+/**
+ * InvalidRentPayer: 'Voucher rent must be refunded to the account that paid for it'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidRentPayerError extends Error {
+  readonly code: number = 0x179c;
+  readonly name: string = "InvalidRentPayer";
+  constructor() {
+    super("Voucher rent must be refunded to the account that paid for it");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, InvalidRentPayerError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179c, () => new InvalidRentPayerError());
+createErrorFromNameLookup.set(
+  "InvalidRentPayer",
+  () => new InvalidRentPayerError()
+);
+
+/**
+ * InvalidExpiry: 'Membership expiry must be in the future'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidExpiryError extends Error {
+  readonly code: number = 0x179d;
+  readonly name: string = "InvalidExpiry";
+  constructor() {
+    super("Membership expiry must be in the future");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, InvalidExpiryError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179d, () => new InvalidExpiryError());
+createErrorFromNameLookup.set("InvalidExpiry", () => new InvalidExpiryError());
+
+/**
+ * MembershipExpired: 'This membership has expired'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MembershipExpiredError extends Error {
+  readonly code: number = 0x179e;
+  readonly name: string = "MembershipExpired";
+  constructor() {
+    super("This membership has expired");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, MembershipExpiredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179e, () => new MembershipExpiredError());
+createErrorFromNameLookup.set(
+  "MembershipExpired",
+  () => new MembershipExpiredError()
+);
+
+/**
+ * ShareChangePending: 'A scheduled share change is pending; distribute to apply it first'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ShareChangePendingError extends Error {
+  readonly code: number = 0x179f;
+  readonly name: string = "ShareChangePending";
+  constructor() {
+    super("A scheduled share change is pending; distribute to apply it first");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, ShareChangePendingError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179f, () => new ShareChangePendingError());
+createErrorFromNameLookup.set(
+  "ShareChangePending",
+  () => new ShareChangePendingError()
+);
+
+/**
+ * InvalidShareSchedule: 'Scheduled share changes must take effect together in the future'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidShareScheduleError extends Error {
+  readonly code: number = 0x17a0;
+  readonly name: string = "InvalidShareSchedule";
+  constructor() {
+    super("Scheduled share changes must take effect together in the future");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, InvalidShareScheduleError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a0, () => new InvalidShareScheduleError());
+createErrorFromNameLookup.set(
+  "InvalidShareSchedule",
+  () => new InvalidShareScheduleError()
+);
+
+/**
+ * ShareTableRequired: 'This mint does not have its own share table'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ShareTableRequiredError extends Error {
+  readonly code: number = 0x17a1;
+  readonly name: string = "ShareTableRequired";
+  constructor() {
+    super("This mint does not have its own share table");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, ShareTableRequiredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a1, () => new ShareTableRequiredError());
+createErrorFromNameLookup.set(
+  "ShareTableRequired",
+  () => new ShareTableRequiredError()
+);
+
+/**
+ * InvalidShareTable: 'Share table total must be greater than zero'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidShareTableError extends Error {
+  readonly code: number = 0x17a2;
+  readonly name: string = "InvalidShareTable";
+  constructor() {
+    super("Share table total must be greater than zero");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, InvalidShareTableError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a2, () => new InvalidShareTableError());
+createErrorFromNameLookup.set(
+  "InvalidShareTable",
+  () => new InvalidShareTableError()
+);
+
+/**
+ * ShareTableLocked: 'A mint's share table cannot change once it has inflow or allocated shares'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ShareTableLockedError extends Error {
+  readonly code: number = 0x17a3;
+  readonly name: string = "ShareTableLocked";
+  constructor() {
+    super(
+      "A mint's share table cannot change once it has inflow or allocated shares"
+    );
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, ShareTableLockedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a3, () => new ShareTableLockedError());
+createErrorFromNameLookup.set(
+  "ShareTableLocked",
+  () => new ShareTableLockedError()
+);
+
+/**
+ * InvalidName: 'Fanout name must not be empty'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidNameError extends Error {
+  readonly code: number = 0x17a4;
+  readonly name: string = "InvalidName";
+  constructor() {
+    super("Fanout name must not be empty");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, InvalidNameError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a4, () => new InvalidNameError());
+createErrorFromNameLookup.set("InvalidName", () => new InvalidNameError());
+
+/**
+ * AccountUpToDate: 'This account already has room for the current layout'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AccountUpToDateError extends Error {
+  readonly code: number = 0x17a5;
+  readonly name: string = "AccountUpToDate";
+  constructor() {
+    super("This account already has room for the current layout");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, AccountUpToDateError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a5, () => new AccountUpToDateError());
+createErrorFromNameLookup.set(
+  "AccountUpToDate",
+  () => new AccountUpToDateError()
+);
+
+/**
+ * InvalidAccountType: 'This account type cannot be migrated'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidAccountTypeError extends Error {
+  readonly code: number = 0x17a6;
+  readonly name: string = "InvalidAccountType";
+  constructor() {
+    super("This account type cannot be migrated");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, InvalidAccountTypeError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a6, () => new InvalidAccountTypeError());
+createErrorFromNameLookup.set(
+  "InvalidAccountType",
+  () => new InvalidAccountTypeError()
+);
+
+/**
+ * MetadataTooLong: 'Fanout metadata URI or description is too long'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MetadataTooLongError extends Error {
+  readonly code: number = 0x17a7;
+  readonly name: string = "MetadataTooLong";
+  constructor() {
+    super("Fanout metadata URI or description is too long");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, MetadataTooLongError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a7, () => new MetadataTooLongError());
+createErrorFromNameLookup.set(
+  "MetadataTooLong",
+  () => new MetadataTooLongError()
+);
+
+/**
+ * LabelTooLong: 'Member label is too long'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class LabelTooLongError extends Error {
+  readonly code: number = 0x17a8;
+  readonly name: string = "LabelTooLong";
+  constructor() {
+    super("Member label is too long");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, LabelTooLongError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a8, () => new LabelTooLongError());
+createErrorFromNameLookup.set("LabelTooLong", () => new LabelTooLongError());
+
+/**
+ * AccountOutdated: 'This account must be migrated first'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AccountOutdatedError extends Error {
+  readonly code: number = 0x17a9;
+  readonly name: string = "AccountOutdated";
+  constructor() {
+    super("This account must be migrated first");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, AccountOutdatedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a9, () => new AccountOutdatedError());
+createErrorFromNameLookup.set(
+  "AccountOutdated",
+  () => new AccountOutdatedError()
+);
+
+/**
+ * InvalidDistributionWindow: 'Distribution windows must be at least one epoch or second long'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidDistributionWindowError extends Error {
+  readonly code: number = 0x17aa;
+  readonly name: string = "InvalidDistributionWindow";
+  constructor() {
+    super("Distribution windows must be at least one epoch or second long");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, InvalidDistributionWindowError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17aa,
+  () => new InvalidDistributionWindowError()
+);
+createErrorFromNameLookup.set(
+  "InvalidDistributionWindow",
+  () => new InvalidDistributionWindowError()
+);
+
+/**
+ * DistributionWindowUsed: 'A distribution already happened in this window'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DistributionWindowUsedError extends Error {
+  readonly code: number = 0x17ab;
+  readonly name: string = "DistributionWindowUsed";
+  constructor() {
+    super("A distribution already happened in this window");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, DistributionWindowUsedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ab, () => new DistributionWindowUsedError());
+createErrorFromNameLookup.set(
+  "DistributionWindowUsed",
+  () => new DistributionWindowUsedError()
+);
+
+/**
+ * NativeMintHasUnpaidInflow: 'wSOL held for the native mint's members must be distributed before unwrapping'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NativeMintHasUnpaidInflowError extends Error {
+  readonly code: number = 0x17ac;
+  readonly name: string = "NativeMintHasUnpaidInflow";
+  constructor() {
+    super(
+      "wSOL held for the native mint's members must be distributed before unwrapping"
+    );
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, NativeMintHasUnpaidInflowError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17ac,
+  () => new NativeMintHasUnpaidInflowError()
+);
+createErrorFromNameLookup.set(
+  "NativeMintHasUnpaidInflow",
+  () => new NativeMintHasUnpaidInflowError()
+);
+
+/**
+ * InvalidMigratedInflow: 'Migrated inflow exceeds the mint inflow this voucher settled against'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidMigratedInflowError extends Error {
+  readonly code: number = 0x17ad;
+  readonly name: string = "InvalidMigratedInflow";
+  constructor() {
+    super(
+      "Migrated inflow exceeds the mint inflow this voucher settled against"
+    );
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, InvalidMigratedInflowError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ad, () => new InvalidMigratedInflowError());
+createErrorFromNameLookup.set(
+  "InvalidMigratedInflow",
+  () => new InvalidMigratedInflowError()
+);
+
+/**
+ * HoldingAccountRequired: 'The holding account must be passed to snapshot inflow before adding a member'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class HoldingAccountRequiredError extends Error {
+  readonly code: number = 0x17ae;
+  readonly name: string = "HoldingAccountRequired";
+  constructor() {
+    super(
+      "The holding account must be passed to snapshot inflow before adding a member"
+    );
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, HoldingAccountRequiredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ae, () => new HoldingAccountRequiredError());
+createErrorFromNameLookup.set(
+  "HoldingAccountRequired",
+  () => new HoldingAccountRequiredError()
+);
+
+/**
+ * MintAccountsRequired: 'Accounts for every mint registered on the fanout must be passed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MintAccountsRequiredError extends Error {
+  readonly code: number = 0x17af;
+  readonly name: string = "MintAccountsRequired";
+  constructor() {
+    super("Accounts for every mint registered on the fanout must be passed");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, MintAccountsRequiredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17af, () => new MintAccountsRequiredError());
+createErrorFromNameLookup.set(
+  "MintAccountsRequired",
+  () => new MintAccountsRequiredError()
+);
+
+/**
+ * ShareHistoryUnavailable: 'The shares this voucher held at an earlier share epoch are no longer known'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ShareHistoryUnavailableError extends Error {
+  readonly code: number = 0x17b0;
+  readonly name: string = "ShareHistoryUnavailable";
+  constructor() {
+    super(
+      "The shares this voucher held at an earlier share epoch are no longer known"
+    );
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, ShareHistoryUnavailableError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b0, () => new ShareHistoryUnavailableError());
+createErrorFromNameLookup.set(
+  "ShareHistoryUnavailable",
+  () => new ShareHistoryUnavailableError()
+);
+
+/**
+ * MembershipNotExpired: 'This membership has not expired'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MembershipNotExpiredError extends Error {
+  readonly code: number = 0x17b1;
+  readonly name: string = "MembershipNotExpired";
+  constructor() {
+    super("This membership has not expired");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, MembershipNotExpiredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b1, () => new MembershipNotExpiredError());
+createErrorFromNameLookup.set(
+  "MembershipNotExpired",
+  () => new MembershipNotExpiredError()
+);
+
+/**
+ * FanoutRequired: 'The fanout must be passed to count a mint migrated onto it'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class FanoutRequiredError extends Error {
+  readonly code: number = 0x17b2;
+  readonly name: string = "FanoutRequired";
+  constructor() {
+    super("The fanout must be passed to count a mint migrated onto it");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, FanoutRequiredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b2, () => new FanoutRequiredError());
+createErrorFromNameLookup.set(
+  "FanoutRequired",
+  () => new FanoutRequiredError()
+);
+
+/**
+ * MintNotCounted: 'This mint must be migrated before it can be used'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MintNotCountedError extends Error {
+  readonly code: number = 0x17b3;
+  readonly name: string = "MintNotCounted";
+  constructor() {
+    super("This mint must be migrated before it can be used");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, MintNotCountedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b3, () => new MintNotCountedError());
+createErrorFromNameLookup.set(
+  "MintNotCounted",
+  () => new MintNotCountedError()
+);
+
+/**
+ * MembershipHasExpiry: 'Shares of a membership with an expiry can't be rescheduled, clear the expiry first'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MembershipHasExpiryError extends Error {
+  readonly code: number = 0x17b4;
+  readonly name: string = "MembershipHasExpiry";
+  constructor() {
+    super(
+      "Shares of a membership with an expiry can't be rescheduled, clear the expiry first"
+    );
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, MembershipHasExpiryError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b4, () => new MembershipHasExpiryError());
+createErrorFromNameLookup.set(
+  "MembershipHasExpiry",
+  () => new MembershipHasExpiryError()
+);
+
+/**
+ * MemberAccountsRequired: 'Accounts for every member of the fanout must be passed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MemberAccountsRequiredError extends Error {
+  readonly code: number = 0x17b5;
+  readonly name: string = "MemberAccountsRequired";
+  constructor() {
+    super("Accounts for every member of the fanout must be passed");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, MemberAccountsRequiredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b5, () => new MemberAccountsRequiredError());
+createErrorFromNameLookup.set(
+  "MemberAccountsRequired",
+  () => new MemberAccountsRequiredError()
+);
+
+/**
+ * InvalidFanoutMetadata: 'The fanout metadata account derived from the fanout must be passed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidFanoutMetadataError extends Error {
+  readonly code: number = 0x17b6;
+  readonly name: string = "InvalidFanoutMetadata";
+  constructor() {
+    super("The fanout metadata account derived from the fanout must be passed");
+    if (typeof Error.captureStackTrace === "function") {
+      Error.captureStackTrace(this, InvalidFanoutMetadataError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b6, () => new InvalidFanoutMetadataError());
+createErrorFromNameLookup.set(
+  "InvalidFanoutMetadata",
+  () => new InvalidFanoutMetadataError()
+);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
@@ -1,13 +1,47 @@
+export * from "./processAddMemberMerkle";
 export * from "./processAddMemberNft";
+export * from "./processAddMemberNftCollection";
 export * from "./processAddMemberWallet";
 export * from "./processDistributeNft";
+export * from "./processDistributeReserve";
 export * from "./processDistributeToken";
+export * from "./processDistributeTreasury";
 export * from "./processDistributeWallet";
 export * from "./processInit";
+export * from "./processInitForMembershipMint";
 export * from "./processInitForMint";
+export * from "./processInitScoped";
+export * from "./processMemberTransferShares";
+export * from "./processMigrateAccount";
+export * from "./processMigrateMintVoucher";
+export * from "./processReleaseExpiredMember";
 export * from "./processRemoveMember";
+export * from "./processResign";
+export * from "./processRotateMemberKey";
+export * from "./processScheduleShareChange";
+export * from "./processSetCrankReward";
+export * from "./processSetDistributionWindow";
+export * from "./processSetFanoutMetadata";
+export * from "./processSetFee";
 export * from "./processSetForTokenMemberStake";
+export * from "./processSetMemberExpiry";
+export * from "./processSetMemberLabel";
+export * from "./processSetMemberTransfers";
+export * from "./processSetMembershipCollection";
+export * from "./processSetMinDistribution";
+export * from "./processSetMintMemberShares";
+export * from "./processSetMintMinDistribution";
+export * from "./processSetMintShareTable";
+export * from "./processSetPayoutDestination";
+export * from "./processSetReserve";
+export * from "./processSetResignationPolicy";
+export * from "./processSetSharesRoot";
 export * from "./processSetTokenMemberStake";
+export * from "./processSetUnstakedPolicy";
+export * from "./processSetUnwrapNativeMint";
+export * from "./processSetWeightedTokenMemberStake";
 export * from "./processSignMetadata";
 export * from "./processTransferShares";
 export * from "./processUnstake";
+export * from "./processUnstakeWeighted";
+export * from "./processUnwrapNativeMint";
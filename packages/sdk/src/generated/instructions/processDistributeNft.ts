@@ -92,7 +92,7 @@ export function createProcessDistributeNftInstruction(
   const keys: web3.AccountMeta[] = [
     {
       pubkey: payer,
-      isWritable: false,
+      isWritable: true,
       isSigner: true,
     },
     {
@@ -102,7 +102,7 @@ export function createProcessDistributeNftInstruction(
     },
     {
       pubkey: membershipMintTokenAccount,
-      isWritable: true,
+      isWritable: false,
       isSigner: false,
     },
     {
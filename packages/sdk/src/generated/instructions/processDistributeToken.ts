@@ -94,7 +94,7 @@ export function createProcessDistributeTokenInstruction(
   const keys: web3.AccountMeta[] = [
     {
       pubkey: payer,
-      isWritable: false,
+      isWritable: true,
       isSigner: true,
     },
     {
@@ -104,7 +104,7 @@ export function createProcessDistributeTokenInstruction(
     },
     {
       pubkey: membershipMintTokenAccount,
-      isWritable: true,
+      isWritable: false,
       isSigner: false,
     },
     {
@@ -88,7 +88,7 @@ export function createProcessDistributeWalletInstruction(
   const keys: web3.AccountMeta[] = [
     {
       pubkey: payer,
-      isWritable: false,
+      isWritable: true,
       isSigner: true,
     },
     {
@@ -0,0 +1,40 @@
+/**
+ * Solita does not render enums with data for beet 0.0.8, so this type is kept by hand in the
+ * layout solita uses for generated types. Keep it in sync with `CrankReward` in state.rs.
+ */
+
+import * as beet from "@metaplex-foundation/beet";
+import { dataEnumBeet } from "../../dataEnum";
+export type CrankReward =
+  | { __kind: "Fixed"; lamports: beet.bignum }
+  | { __kind: "BasisPoints"; basisPoints: number; maxLamports: beet.bignum };
+
+/**
+ * @category userTypes
+ * @category generated
+ */
+export const crankRewardBeet = dataEnumBeet<CrankReward>(
+  [
+    [
+      "Fixed",
+      new beet.BeetArgsStruct<{ lamports: beet.bignum }>(
+        [["lamports", beet.u64]],
+        "CrankReward.Fixed"
+      ),
+    ],
+    [
+      "BasisPoints",
+      new beet.BeetArgsStruct<{
+        basisPoints: number;
+        maxLamports: beet.bignum;
+      }>(
+        [
+          ["basisPoints", beet.u16],
+          ["maxLamports", beet.u64],
+        ],
+        "CrankReward.BasisPoints"
+      ),
+    ],
+  ],
+  "CrankReward"
+);
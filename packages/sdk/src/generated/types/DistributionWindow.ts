@@ -0,0 +1,34 @@
+/**
+ * Solita does not render enums with data for beet 0.0.8, so this type is kept by hand in the
+ * layout solita uses for generated types. Keep it in sync with `DistributionWindow` in state.rs.
+ */
+
+import * as beet from "@metaplex-foundation/beet";
+import { dataEnumBeet } from "../../dataEnum";
+export type DistributionWindow =
+  | { __kind: "Epochs"; epochs: beet.bignum }
+  | { __kind: "Seconds"; seconds: beet.bignum };
+
+/**
+ * @category userTypes
+ * @category generated
+ */
+export const distributionWindowBeet = dataEnumBeet<DistributionWindow>(
+  [
+    [
+      "Epochs",
+      new beet.BeetArgsStruct<{ epochs: beet.bignum }>(
+        [["epochs", beet.u64]],
+        "DistributionWindow.Epochs"
+      ),
+    ],
+    [
+      "Seconds",
+      new beet.BeetArgsStruct<{ seconds: beet.bignum }>(
+        [["seconds", beet.u64]],
+        "DistributionWindow.Seconds"
+      ),
+    ],
+  ],
+  "DistributionWindow"
+);
@@ -6,11 +6,16 @@
  */
 
 import * as beet from "@metaplex-foundation/beet";
+import {
+  FanoutMetadataArgs,
+  fanoutMetadataArgsBeet,
+} from "./FanoutMetadataArgs";
 export type InitializeFanoutArgs = {
   bumpSeed: number;
   nativeAccountBumpSeed: number;
   name: string;
   totalShares: beet.bignum;
+  metadata: beet.COption<FanoutMetadataArgs>;
 };
 
 /**
@@ -24,6 +29,7 @@ export const initializeFanoutArgsBeet =
       ["nativeAccountBumpSeed", beet.u8],
       ["name", beet.utf8String],
       ["totalShares", beet.u64],
+      ["metadata", beet.coption(fanoutMetadataArgsBeet)],
     ],
     "InitializeFanoutArgs"
   );
@@ -14,6 +14,7 @@ export enum MembershipModel {
   Wallet,
   Token,
   NFT,
+  Merkle,
 }
 
 /**
@@ -1,3 +1,10 @@
 export * from "./AddMemberArgs";
+export * from "./AddMemberWithProofArgs";
+export * from "./CrankReward";
+export * from "./DistributionWindow";
+export * from "./DistributionWindowScope";
+export * from "./FanoutMetadataArgs";
 export * from "./InitializeFanoutArgs";
 export * from "./MembershipModel";
+export * from "./ResignationPolicy";
+export * from "./UnstakedPolicy";
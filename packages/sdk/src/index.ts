@@ -9,6 +9,7 @@ import {
   AccountInfo,
   Connection,
   Finality,
+  Keypair,
   PublicKey,
   RpcResponseAndContext,
   SignatureResult,
@@ -27,6 +28,7 @@ import {
   createProcessDistributeWalletInstruction,
   createProcessInitForMintInstruction,
   createProcessInitInstruction,
+  createProcessInitScopedInstruction,
   createProcessRemoveMemberInstruction,
   createProcessSetForTokenMemberStakeInstruction,
   createProcessSetTokenMemberStakeInstruction,
@@ -34,7 +36,7 @@ import {
   createProcessTransferSharesInstruction,
   createProcessUnstakeInstruction,
 } from "./generated/instructions";
-import { MembershipModel } from "./generated/types";
+import { FanoutMetadataArgs, MembershipModel } from "./generated/types";
 import { Fanout } from "./generated/accounts";
 import { MetadataProgram } from "@metaplex-foundation/mpl-token-metadata";
 import {
@@ -46,6 +48,7 @@ import bs58 from "bs58";
 import { getTokenAccount } from "@project-serum/common";
 import { chunks } from "./utils";
 
+export * from "./generated/instructions";
 export * from "./generated/types";
 export * from "./generated/accounts";
 export * from "./generated/errors";
@@ -55,6 +58,9 @@ interface InitializeFanoutArgs {
   membershipModel: MembershipModel;
   totalShares: number;
   mint?: PublicKey;
+  metadata?: FanoutMetadataArgs;
+  // Scopes the fanout address to this key, which must sign; a keypair is added to the signers
+  base?: PublicKey | Keypair;
 }
 
 interface InitializeFanoutForMintArgs {
@@ -281,12 +287,25 @@ export class FanoutClient {
     return res;
   }
 
+  static async fanoutMetadata(
+    fanout: PublicKey,
+    programId: PublicKey = FanoutClient.ID
+  ): Promise<[PublicKey, number]> {
+    return await PublicKey.findProgramAddress(
+      [Buffer.from("fanout-metadata"), fanout.toBuffer()],
+      programId
+    );
+  }
+
   static async fanoutKey(
     name: String,
-    programId: PublicKey = FanoutClient.ID
+    programId: PublicKey = FanoutClient.ID,
+    base?: PublicKey
   ): Promise<[PublicKey, number]> {
     return await PublicKey.findProgramAddress(
-      [Buffer.from("fanout-config"), Buffer.from(name)],
+      base
+        ? [Buffer.from("fanout-config"), base.toBuffer(), Buffer.from(name)]
+        : [Buffer.from("fanout-config"), Buffer.from(name)],
       programId
     );
   }
@@ -359,13 +378,20 @@ export class FanoutClient {
   ): Promise<
     InstructionResult<{ fanout: PublicKey; nativeAccount: PublicKey }>
   > {
+    const base =
+      opts.base && "secretKey" in opts.base ? opts.base.publicKey : opts.base;
     const [fanoutConfig, fanoutConfigBumpSeed] = await FanoutClient.fanoutKey(
-      opts.name
+      opts.name,
+      FanoutClient.ID,
+      base
     );
     const [holdingAccount, holdingAccountBumpSeed] =
       await FanoutClient.nativeAccount(fanoutConfig);
     const instructions: TransactionInstruction[] = [];
     const signers: Signer[] = [];
+    if (opts.base && "secretKey" in opts.base) {
+      signers.push(opts.base);
+    }
     let membershipMint = NATIVE_MINT;
     if (opts.membershipModel == MembershipModel.Token) {
       if (!opts.mint) {
@@ -375,25 +401,46 @@ export class FanoutClient {
       }
       membershipMint = opts.mint;
     }
-    instructions.push(
-      createProcessInitInstruction(
-        {
-          authority: this.wallet.publicKey,
-          holdingAccount: holdingAccount,
-          fanout: fanoutConfig,
-          membershipMint: membershipMint,
-        },
-        {
-          args: {
-            bumpSeed: fanoutConfigBumpSeed,
-            nativeAccountBumpSeed: holdingAccountBumpSeed,
-            totalShares: opts.totalShares,
-            name: opts.name,
+    const args = {
+      args: {
+        bumpSeed: fanoutConfigBumpSeed,
+        nativeAccountBumpSeed: holdingAccountBumpSeed,
+        totalShares: opts.totalShares,
+        name: opts.name,
+        metadata: opts.metadata ?? null,
+      },
+      model: opts.membershipModel,
+    };
+    const init = base
+      ? createProcessInitScopedInstruction(
+          {
+            authority: this.wallet.publicKey,
+            base,
+            holdingAccount: holdingAccount,
+            fanout: fanoutConfig,
+            membershipMint: membershipMint,
           },
-          model: opts.membershipModel,
-        }
-      )
-    );
+          args
+        )
+      : createProcessInitInstruction(
+          {
+            authority: this.wallet.publicKey,
+            holdingAccount: holdingAccount,
+            fanout: fanoutConfig,
+            membershipMint: membershipMint,
+          },
+          args
+        );
+    // Metadata is written to its own account, passed after the instruction's accounts
+    if (opts.metadata) {
+      const [fanoutMetadata] = await FanoutClient.fanoutMetadata(fanoutConfig);
+      init.keys.push({
+        pubkey: fanoutMetadata,
+        isWritable: true,
+        isSigner: false,
+      });
+    }
+    instructions.push(init);
     return {
       output: {
         fanout: fanoutConfig,
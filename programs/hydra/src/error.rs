@@ -22,6 +22,12 @@ impl OrArithError<u128> for Option<u128> {
     }
 }
 
+impl OrArithError<i128> for Option<i128> {
+    fn or_arith_error(self) -> StdResult<i128, error::Error> {
+        self.ok_or(HydraError::BadArtithmetic.into())
+    }
+}
+
 #[error_code]
 pub enum HydraError {
     #[msg("Encountered an arithmetic error")]
@@ -93,5 +99,143 @@ pub enum HydraError {
     RemoveSharesMustBeZero,
 
     #[msg("Sending Sol to a SPL token destination will render the sol unusable")]
-    InvalidCloseAccountDestination
+    InvalidCloseAccountDestination,
+
+    #[msg("Wrapped SOL is unwrapped into the native holding account on this fanout")]
+    NativeMintUnwrapped,
+
+    #[msg("Wrapped SOL unwrapping is not enabled on this fanout")]
+    NativeMintUnwrapDisabled,
+
+    #[msg("Fee basis points must be at most 10000")]
+    InvalidFee,
+
+    #[msg("Fee recipient does not match the config")]
+    InvalidFeeRecipient,
+
+    #[msg("Crank reward basis points must be at most 10000")]
+    InvalidCrankReward,
+
+    #[msg("This mint membership voucher has already been migrated")]
+    AlreadyMigrated,
+
+    #[msg("The NFT is not a verified member of the fanout collection")]
+    InvalidCollection,
+
+    #[msg("This fanout is not bound to a collection")]
+    CollectionRequired,
+
+    #[msg("The merkle proof does not match the committed root")]
+    InvalidProof,
+
+    #[msg("This fanout has no committed shares root")]
+    SharesRootRequired,
+
+    #[msg("Reserve is not supported on this membership model")]
+    ReserveNotSupported,
+
+    #[msg("Reserve account does not match the config")]
+    InvalidReserve,
+
+    #[msg("The reserve must claim its native inflow before it is changed")]
+    ReserveHasUnclaimedInflow,
+
+    #[msg("Membership mint weight must be greater than zero")]
+    InvalidWeight,
+
+    #[msg("The primary membership mint cannot be registered as a weighted mint")]
+    InvalidMembershipMint,
+
+    #[msg("Weighted membership mint stakes must be unstaked first")]
+    WeightedStakeRemaining,
+
+    #[msg("Payout destination does not match the membership voucher")]
+    InvalidPayoutDestination,
+
+    #[msg("Only the member or the fanout authority can rotate a member key")]
+    InvalidRotationSigner,
+
+    #[msg("Members cannot transfer shares on this fanout")]
+    MemberTransfersDisabled,
+
+    #[msg("Voucher rent must be refunded to the account that paid for it")]
+    InvalidRentPayer,
+
+    #[msg("Membership expiry must be in the future")]
+    InvalidExpiry,
+
+    #[msg("This membership has expired")]
+    MembershipExpired,
+
+    #[msg("A scheduled share change is pending; distribute to apply it first")]
+    ShareChangePending,
+
+    #[msg("Scheduled share changes must take effect together in the future")]
+    InvalidShareSchedule,
+
+    #[msg("This mint does not have its own share table")]
+    ShareTableRequired,
+
+    #[msg("Share table total must be greater than zero")]
+    InvalidShareTable,
+
+    #[msg("A mint's share table cannot change once it has inflow or allocated shares")]
+    ShareTableLocked,
+
+    #[msg("Fanout name must not be empty")]
+    InvalidName,
+
+    #[msg("This account already has room for the current layout")]
+    AccountUpToDate,
+
+    #[msg("This account type cannot be migrated")]
+    InvalidAccountType,
+
+    #[msg("Fanout metadata URI or description is too long")]
+    MetadataTooLong,
+
+    #[msg("Member label is too long")]
+    LabelTooLong,
+
+    #[msg("This account must be migrated first")]
+    AccountOutdated,
+
+    #[msg("Distribution windows must be at least one epoch or second long")]
+    InvalidDistributionWindow,
+
+    #[msg("A distribution already happened in this window")]
+    DistributionWindowUsed,
+
+    #[msg("wSOL held for the native mint's members must be distributed before unwrapping")]
+    NativeMintHasUnpaidInflow,
+
+    #[msg("Migrated inflow exceeds the mint inflow this voucher settled against")]
+    InvalidMigratedInflow,
+
+    #[msg("The holding account must be passed to snapshot inflow before adding a member")]
+    HoldingAccountRequired,
+
+    #[msg("Accounts for every mint registered on the fanout must be passed")]
+    MintAccountsRequired,
+
+    #[msg("The shares this voucher held at an earlier share epoch are no longer known")]
+    ShareHistoryUnavailable,
+
+    #[msg("This membership has not expired")]
+    MembershipNotExpired,
+
+    #[msg("The fanout must be passed to count a mint migrated onto it")]
+    FanoutRequired,
+
+    #[msg("This mint must be migrated before it can be used")]
+    MintNotCounted,
+
+    #[msg("Shares of a membership with an expiry can't be rescheduled, clear the expiry first")]
+    MembershipHasExpiry,
+
+    #[msg("Accounts for every member of the fanout must be passed")]
+    MemberAccountsRequired,
+
+    #[msg("The fanout metadata account derived from the fanout must be passed")]
+    InvalidFanoutMetadata,
 }
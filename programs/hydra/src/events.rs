@@ -0,0 +1,123 @@
+use crate::state::MembershipModel;
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct FanoutInitialized {
+    pub fanout: Pubkey,
+    pub authority: Pubkey,
+    pub name: String,
+    pub membership_model: MembershipModel,
+    pub membership_mint: Option<Pubkey>,
+    pub total_shares: u64,
+}
+
+#[event]
+pub struct FanoutUpdated {
+    pub fanout: Pubkey,
+}
+
+#[event]
+pub struct MintRegistered {
+    pub fanout: Pubkey,
+    pub fanout_for_mint: Pubkey,
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+}
+
+#[event]
+pub struct MemberUpdated {
+    pub fanout: Pubkey,
+    pub membership_key: Pubkey,
+}
+
+#[event]
+pub struct MemberKeyRotated {
+    pub fanout: Pubkey,
+    pub membership_key: Pubkey,
+    pub new_membership_key: Pubkey,
+}
+
+#[event]
+pub struct MembershipExpired {
+    pub fanout: Pubkey,
+    pub membership_key: Pubkey,
+    pub shares: u64,
+}
+
+#[event]
+pub struct MemberAdded {
+    pub fanout: Pubkey,
+    pub membership_key: Pubkey,
+    pub membership_voucher: Pubkey,
+    pub shares: u64,
+}
+
+#[event]
+pub struct MemberRemoved {
+    pub fanout: Pubkey,
+    pub membership_key: Pubkey,
+    pub membership_voucher: Pubkey,
+}
+
+#[event]
+pub struct SharesTransferred {
+    pub fanout: Pubkey,
+    pub from_membership_key: Pubkey,
+    pub to_membership_key: Pubkey,
+    pub shares: u64,
+}
+
+#[event]
+pub struct MemberStaked {
+    pub fanout: Pubkey,
+    pub member: Pubkey,
+    pub shares: u64,
+    pub total_staked_shares: Option<u64>,
+}
+
+#[event]
+pub struct MemberUnstaked {
+    pub fanout: Pubkey,
+    pub member: Pubkey,
+    pub shares: u64,
+    pub total_staked_shares: Option<u64>,
+}
+
+#[event]
+pub struct Distributed {
+    pub fanout: Pubkey,
+    pub membership_key: Pubkey,
+    pub member: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    pub fee: u64,
+    pub crank_reward: u64,
+    pub total_inflow: u64,
+}
+
+#[event]
+pub struct MintVoucherMigrated {
+    pub fanout: Pubkey,
+    pub membership_key: Pubkey,
+    pub mint: Pubkey,
+    pub total_inflow: u64,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub version: u8,
+}
+
+#[event]
+pub struct NativeMintUnwrapped {
+    pub fanout: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct MembershipMintRegistered {
+    pub fanout: Pubkey,
+    pub mint: Pubkey,
+    pub weight: u64,
+}
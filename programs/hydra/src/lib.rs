@@ -1,25 +1,37 @@
 pub mod error;
+pub mod events;
 pub mod processors;
 pub mod state;
 pub mod utils;
 
 use anchor_lang::prelude::*;
 use processors::*;
-use state::MembershipModel;
+use state::{
+    CrankReward, DistributionWindow, DistributionWindowScope, MembershipModel, ResignationPolicy,
+    UnstakedPolicy,
+};
 
 declare_id!("hyDQ4Nz1eYyegS6JfenyKwKzYxRsCWCriYSAjtzP4Vg");
 #[program]
 pub mod hydra {
     use super::*;
 
-    pub fn process_init(
-        ctx: Context<InitializeFanout>,
+    pub fn process_init<'info>(
+        ctx: Context<'_, '_, '_, 'info, InitializeFanout<'info>>,
         args: InitializeFanoutArgs,
         model: MembershipModel,
     ) -> Result<()> {
         init(ctx, args, model)
     }
 
+    pub fn process_init_scoped<'info>(
+        ctx: Context<'_, '_, '_, 'info, InitializeScopedFanout<'info>>,
+        args: InitializeFanoutArgs,
+        model: MembershipModel,
+    ) -> Result<()> {
+        init_scoped(ctx, args, model)
+    }
+
     pub fn process_init_for_mint(
         ctx: Context<InitializeFanoutForMint>,
         bump_seed: u8,
@@ -27,20 +39,41 @@ pub mod hydra {
         init_for_mint(ctx, bump_seed)
     }
 
-    pub fn process_add_member_wallet(
-        ctx: Context<AddMemberWallet>,
+    pub fn process_init_for_membership_mint(
+        ctx: Context<InitializeFanoutForMembershipMint>,
+        weight: u64,
+    ) -> Result<()> {
+        init_for_membership_mint(ctx, weight)
+    }
+
+    pub fn process_add_member_wallet<'info>(
+        ctx: Context<'_, '_, '_, 'info, AddMemberWallet<'info>>,
         args: AddMemberArgs,
     ) -> Result<()> {
         add_member_wallet(ctx, args)
     }
 
-    pub fn process_add_member_nft(
-        ctx: Context<AddMemberWithNFT>,
+    pub fn process_add_member_nft<'info>(
+        ctx: Context<'_, '_, '_, 'info, AddMemberWithNFT<'info>>,
         args: AddMemberArgs,
     ) -> Result<()> {
         add_member_nft(ctx, args)
     }
 
+    pub fn process_add_member_nft_collection<'info>(
+        ctx: Context<'_, '_, '_, 'info, AddMemberWithCollectionNFT<'info>>,
+        args: AddMemberWithProofArgs,
+    ) -> Result<()> {
+        add_member_nft_collection(ctx, args)
+    }
+
+    pub fn process_add_member_merkle<'info>(
+        ctx: Context<'_, '_, '_, 'info, AddMemberWithProof<'info>>,
+        args: AddMemberWithProofArgs,
+    ) -> Result<()> {
+        add_member_merkle(ctx, args)
+    }
+
     pub fn process_set_token_member_stake(
         ctx: Context<SetTokenMemberStake>,
         shares: u64,
@@ -48,6 +81,13 @@ pub mod hydra {
         set_token_member_stake(ctx, shares)
     }
 
+    pub fn process_set_weighted_token_member_stake<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetWeightedTokenMemberStake<'info>>,
+        shares: u64,
+    ) -> Result<()> {
+        set_weighted_token_member_stake(ctx, shares)
+    }
+
     pub fn process_set_for_token_member_stake(
         ctx: Context<SetForTokenMemberStake>,
         shares: u64,
@@ -55,27 +95,41 @@ pub mod hydra {
         set_for_token_member_stake(ctx, shares)
     }
 
-    pub fn process_distribute_nft(
-        ctx: Context<DistributeNftMember>,
+    pub fn process_distribute_nft<'info>(
+        ctx: Context<'_, '_, '_, 'info, DistributeNftMember<'info>>,
         distribute_for_mint: bool,
     ) -> Result<()> {
         distribute_for_nft(ctx, distribute_for_mint)
     }
 
-    pub fn process_distribute_wallet(
-        ctx: Context<DistributeWalletMember>,
+    pub fn process_distribute_wallet<'info>(
+        ctx: Context<'_, '_, '_, 'info, DistributeWalletMember<'info>>,
         distribute_for_mint: bool,
     ) -> Result<()> {
         distribute_for_wallet(ctx, distribute_for_mint)
     }
 
-    pub fn process_distribute_token(
-        ctx: Context<DistributeTokenMember>,
+    pub fn process_distribute_token<'info>(
+        ctx: Context<'_, '_, '_, 'info, DistributeTokenMember<'info>>,
         distribute_for_mint: bool,
     ) -> Result<()> {
         distribute_for_token(ctx, distribute_for_mint)
     }
 
+    pub fn process_distribute_reserve(
+        ctx: Context<DistributeReserve>,
+        distribute_for_mint: bool,
+    ) -> Result<()> {
+        distribute_for_reserve(ctx, distribute_for_mint)
+    }
+
+    pub fn process_distribute_treasury(
+        ctx: Context<DistributeTreasury>,
+        distribute_for_mint: bool,
+    ) -> Result<()> {
+        distribute_for_treasury(ctx, distribute_for_mint)
+    }
+
     pub fn process_sign_metadata(ctx: Context<SignMetadata>) -> Result<()> {
         sign_metadata(ctx)
     }
@@ -84,11 +138,185 @@ pub mod hydra {
         transfer_shares(ctx, shares)
     }
 
+    pub fn process_member_transfer_shares<'info>(
+        ctx: Context<'_, '_, '_, 'info, MemberTransferShares<'info>>,
+        shares: u64,
+    ) -> Result<()> {
+        member_transfer_shares(ctx, shares)
+    }
+
     pub fn process_unstake(ctx: Context<UnStakeTokenMember>) -> Result<()> {
         unstake(ctx)
     }
 
+    pub fn process_unstake_weighted(ctx: Context<UnStakeWeightedTokenMember>) -> Result<()> {
+        unstake_weighted(ctx)
+    }
+
     pub fn process_remove_member(ctx: Context<RemoveMember>) -> Result<()> {
         remove_member(ctx)
     }
+
+    pub fn process_set_unwrap_native_mint<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetUnwrapNativeMint<'info>>,
+        unwrap_native_mint: bool,
+    ) -> Result<()> {
+        set_unwrap_native_mint(ctx, unwrap_native_mint)
+    }
+
+    pub fn process_set_fee(ctx: Context<SetFee>, fee_basis_points: u16) -> Result<()> {
+        set_fee(ctx, fee_basis_points)
+    }
+
+    pub fn process_set_crank_reward(
+        ctx: Context<SetCrankReward>,
+        crank_reward: Option<CrankReward>,
+    ) -> Result<()> {
+        set_crank_reward(ctx, crank_reward)
+    }
+
+    pub fn process_set_membership_collection(
+        ctx: Context<SetMembershipCollection>,
+        shares_per_nft: u64,
+    ) -> Result<()> {
+        set_membership_collection(ctx, shares_per_nft)
+    }
+
+    pub fn process_set_shares_root(
+        ctx: Context<SetSharesRoot>,
+        root: Option<[u8; 32]>,
+    ) -> Result<()> {
+        set_shares_root(ctx, root)
+    }
+
+    pub fn process_set_reserve(ctx: Context<SetReserve>, enabled: bool) -> Result<()> {
+        set_reserve(ctx, enabled)
+    }
+
+    pub fn process_set_unstaked_policy(
+        ctx: Context<SetUnstakedPolicy>,
+        unstaked_policy: UnstakedPolicy,
+    ) -> Result<()> {
+        set_unstaked_policy(ctx, unstaked_policy)
+    }
+
+    pub fn process_set_member_transfers(
+        ctx: Context<SetMemberTransfers>,
+        member_transfers_enabled: bool,
+    ) -> Result<()> {
+        set_member_transfers(ctx, member_transfers_enabled)
+    }
+
+    pub fn process_set_resignation_policy(
+        ctx: Context<SetResignationPolicy>,
+        resignation_policy: ResignationPolicy,
+    ) -> Result<()> {
+        set_resignation_policy(ctx, resignation_policy)
+    }
+
+    pub fn process_resign<'info>(ctx: Context<'_, '_, '_, 'info, Resign<'info>>) -> Result<()> {
+        resign(ctx)
+    }
+
+    pub fn process_set_member_expiry<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetMemberExpiry<'info>>,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        set_member_expiry(ctx, expires_at)
+    }
+
+    pub fn process_release_expired_member<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReleaseExpiredMember<'info>>,
+    ) -> Result<()> {
+        release_expired_member(ctx)
+    }
+
+    pub fn process_set_member_label(
+        ctx: Context<SetMemberLabel>,
+        label: Option<String>,
+    ) -> Result<()> {
+        set_member_label(ctx, label)
+    }
+
+    pub fn process_schedule_share_change<'info>(
+        ctx: Context<'_, '_, '_, 'info, ScheduleShareChange<'info>>,
+        shares: u64,
+        effective_at: i64,
+    ) -> Result<()> {
+        schedule_member_share_change(ctx, shares, effective_at)
+    }
+
+    pub fn process_set_mint_share_table(
+        ctx: Context<SetMintShareTable>,
+        total_shares: Option<u64>,
+    ) -> Result<()> {
+        set_mint_share_table(ctx, total_shares)
+    }
+
+    pub fn process_set_min_distribution(
+        ctx: Context<SetMinDistribution>,
+        min_distribution: u64,
+    ) -> Result<()> {
+        set_min_distribution(ctx, min_distribution)
+    }
+
+    pub fn process_set_mint_min_distribution(
+        ctx: Context<SetMintMinDistribution>,
+        min_distribution: u64,
+    ) -> Result<()> {
+        set_mint_min_distribution(ctx, min_distribution)
+    }
+
+    pub fn process_set_distribution_window(
+        ctx: Context<SetDistributionWindow>,
+        distribution_window: Option<DistributionWindow>,
+        distribution_window_scope: DistributionWindowScope,
+    ) -> Result<()> {
+        set_distribution_window(ctx, distribution_window, distribution_window_scope)
+    }
+
+    pub fn process_set_fanout_metadata(
+        ctx: Context<SetFanoutMetadata>,
+        uri: String,
+        description: String,
+    ) -> Result<()> {
+        set_fanout_metadata(ctx, uri, description)
+    }
+
+    pub fn process_set_mint_member_shares(
+        ctx: Context<SetMintMemberShares>,
+        shares: u64,
+    ) -> Result<()> {
+        set_mint_member_shares(ctx, shares)
+    }
+
+    pub fn process_set_payout_destination(
+        ctx: Context<SetPayoutDestination>,
+        payout_destination: Option<Pubkey>,
+    ) -> Result<()> {
+        set_payout_destination(ctx, payout_destination)
+    }
+
+    pub fn process_rotate_member_key<'info>(
+        ctx: Context<'_, '_, '_, 'info, RotateMemberKey<'info>>,
+    ) -> Result<()> {
+        rotate_member_key(ctx)
+    }
+
+    pub fn process_migrate_mint_voucher(
+        ctx: Context<MigrateMintVoucher>,
+        total_inflow: u64,
+    ) -> Result<()> {
+        migrate_mint_voucher(ctx, total_inflow)
+    }
+
+    pub fn process_migrate_account<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateAccount<'info>>,
+    ) -> Result<()> {
+        migrate_account(ctx)
+    }
+
+    pub fn process_unwrap_native_mint(ctx: Context<UnwrapNativeMint>) -> Result<()> {
+        unwrap_native_mint(ctx)
+    }
 }
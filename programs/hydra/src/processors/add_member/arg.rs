@@ -4,3 +4,9 @@ use anchor_lang::prelude::*;
 pub struct AddMemberArgs {
     pub shares: u64,
 }
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct AddMemberWithProofArgs {
+    pub shares: u64,
+    pub proof: Vec<[u8; 32]>,
+}
@@ -0,0 +1,75 @@
+use super::arg::AddMemberWithProofArgs;
+use crate::error::HydraError;
+use crate::events::MemberAdded;
+use crate::state::{
+    Fanout, FanoutMembershipVoucher, ACCOUNT_VERSION, FANOUT_MEMBERSHIP_VOUCHER_SIZE,
+};
+use crate::utils::logic::calculation::*;
+use crate::utils::start_mint_vouchers_for_add;
+use crate::utils::validation::*;
+use crate::MembershipModel;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(args: AddMemberWithProofArgs)]
+pub struct AddMemberWithProof<'info> {
+    #[account(mut)]
+    pub member: Signer<'info>,
+    #[account(
+    mut,
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+    #[account(
+    init,
+    space = FANOUT_MEMBERSHIP_VOUCHER_SIZE,
+    seeds = [b"fanout-membership", fanout.key().as_ref(), member.key().as_ref()],
+    bump,
+    payer = member
+    )]
+    pub membership_account: Account<'info, FanoutMembershipVoucher>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn add_member_merkle<'info>(
+    ctx: Context<'_, '_, '_, 'info, AddMemberWithProof<'info>>,
+    args: AddMemberWithProofArgs,
+) -> Result<()> {
+    let fanout = &mut ctx.accounts.fanout;
+    let member = &ctx.accounts.member;
+    let membership_account = &mut ctx.accounts.membership_account;
+    assert_membership_model(fanout, MembershipModel::Merkle)?;
+    assert_owned_by(&member.to_account_info(), &System::id())?;
+    let root = fanout.shares_root.ok_or(HydraError::SharesRootRequired)?;
+    assert_valid_proof(
+        &args.proof,
+        &root,
+        membership_leaf(&member.key(), args.shares),
+    )?;
+    update_inflow_for_add(fanout, ctx.remaining_accounts, &ctx.accounts.rent)?;
+    start_mint_vouchers_for_add(
+        fanout,
+        &member.key(),
+        ctx.remaining_accounts,
+        &ctx.accounts.rent,
+        &ctx.accounts.system_program,
+        &ctx.accounts.member.to_account_info(),
+    )?;
+    update_fanout_for_add(fanout, args.shares)?;
+    membership_account.membership_key = member.key();
+    membership_account.shares = args.shares;
+    membership_account.bump_seed = *ctx.bumps.get("membership_account").unwrap();
+    membership_account.version = ACCOUNT_VERSION;
+    membership_account.fanout = fanout.key();
+    // Unclaimed leaves earn from init like any reserved shares, unless a reserve collected it
+    update_voucher_for_add(fanout, membership_account);
+    emit!(MemberAdded {
+        fanout: fanout.key(),
+        membership_key: membership_account.membership_key,
+        membership_voucher: membership_account.key(),
+        shares: args.shares,
+    });
+    Ok(())
+}
@@ -1,3 +1,5 @@
 pub mod arg;
+pub mod merkle;
 pub mod nft;
+pub mod nft_collection;
 pub mod wallet;
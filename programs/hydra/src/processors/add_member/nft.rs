@@ -1,7 +1,11 @@
 use super::arg::AddMemberArgs;
 
-use crate::state::{Fanout, FanoutMembershipVoucher, FANOUT_MEMBERSHIP_VOUCHER_SIZE};
+use crate::events::MemberAdded;
+use crate::state::{
+    Fanout, FanoutMembershipVoucher, ACCOUNT_VERSION, FANOUT_MEMBERSHIP_VOUCHER_SIZE,
+};
 use crate::utils::logic::calculation::*;
+use crate::utils::start_mint_vouchers_for_add;
 use crate::utils::validation::{assert_membership_model, assert_owned_by, assert_valid_metadata};
 use crate::MembershipModel;
 use anchor_lang::prelude::*;
@@ -14,7 +18,7 @@ pub struct AddMemberWithNFT<'info> {
     pub authority: Signer<'info>,
     #[account(
     mut,
-    seeds = [b"fanout-config", fanout.name.as_bytes()],
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
     has_one= authority,
     bump = fanout.bump_seed,
     )]
@@ -34,7 +38,10 @@ pub struct AddMemberWithNFT<'info> {
     pub rent: Sysvar<'info, Rent>,
     pub token_program: Program<'info, Token>,
 }
-pub fn add_member_nft(ctx: Context<AddMemberWithNFT>, args: AddMemberArgs) -> Result<()> {
+pub fn add_member_nft<'info>(
+    ctx: Context<'_, '_, '_, 'info, AddMemberWithNFT<'info>>,
+    args: AddMemberArgs,
+) -> Result<()> {
     let fanout = &mut ctx.accounts.fanout;
     let membership_account = &mut ctx.accounts.membership_account;
     let metadata = &ctx.accounts.metadata;
@@ -42,10 +49,27 @@ pub fn add_member_nft(ctx: Context<AddMemberWithNFT>, args: AddMemberArgs) -> Re
     assert_owned_by(metadata, &mpl_token_metadata::id())?;
     assert_membership_model(fanout, MembershipModel::NFT)?;
     assert_valid_metadata(metadata, &mint.to_account_info())?;
+    update_inflow_for_add(fanout, ctx.remaining_accounts, &ctx.accounts.rent)?;
+    start_mint_vouchers_for_add(
+        fanout,
+        &ctx.accounts.mint.key(),
+        ctx.remaining_accounts,
+        &ctx.accounts.rent,
+        &ctx.accounts.system_program,
+        &ctx.accounts.authority.to_account_info(),
+    )?;
     update_fanout_for_add(fanout, args.shares)?;
     membership_account.membership_key = ctx.accounts.mint.to_account_info().key();
     membership_account.shares = args.shares;
     membership_account.bump_seed = *ctx.bumps.get("membership_account").unwrap();
+    membership_account.version = ACCOUNT_VERSION;
     membership_account.fanout = fanout.key();
+    update_voucher_for_add(fanout, membership_account);
+    emit!(MemberAdded {
+        fanout: fanout.key(),
+        membership_key: membership_account.membership_key,
+        membership_voucher: membership_account.key(),
+        shares: membership_account.shares,
+    });
     Ok(())
 }
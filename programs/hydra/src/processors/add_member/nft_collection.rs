@@ -0,0 +1,96 @@
+use super::arg::AddMemberWithProofArgs;
+use crate::error::HydraError;
+use crate::events::MemberAdded;
+use crate::state::{
+    Fanout, FanoutMembershipVoucher, ACCOUNT_VERSION, FANOUT_MEMBERSHIP_VOUCHER_SIZE,
+};
+use crate::utils::logic::calculation::*;
+use crate::utils::start_mint_vouchers_for_add;
+use crate::utils::validation::*;
+use crate::MembershipModel;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+#[instruction(args: AddMemberWithProofArgs)]
+pub struct AddMemberWithCollectionNFT<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+    #[account(
+    mut,
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+    #[account(
+    init,
+    space = FANOUT_MEMBERSHIP_VOUCHER_SIZE,
+    seeds = [b"fanout-membership", fanout.key().as_ref(), mint.key().as_ref()],
+    bump,
+    payer = holder
+    )]
+    pub membership_account: Account<'info, FanoutMembershipVoucher>,
+    pub mint: Account<'info, Mint>,
+    pub membership_mint_token_account: Account<'info, TokenAccount>,
+    /// CHECK: Checked in program
+    pub metadata: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn add_member_nft_collection<'info>(
+    ctx: Context<'_, '_, '_, 'info, AddMemberWithCollectionNFT<'info>>,
+    args: AddMemberWithProofArgs,
+) -> Result<()> {
+    let fanout = &mut ctx.accounts.fanout;
+    let membership_account = &mut ctx.accounts.membership_account;
+    let metadata = &ctx.accounts.metadata;
+    let mint = &ctx.accounts.mint;
+    assert_owned_by(metadata, &mpl_token_metadata::id())?;
+    assert_membership_model(fanout, MembershipModel::NFT)?;
+    let collection = fanout
+        .membership_collection
+        .ok_or(HydraError::CollectionRequired)?;
+    assert_holding(
+        &ctx.accounts.holder.to_account_info(),
+        &ctx.accounts.membership_mint_token_account,
+        &mint.to_account_info(),
+    )?;
+    let meta = assert_valid_metadata(metadata, &mint.to_account_info())?;
+    assert_verified_collection(&meta, &collection)?;
+    let shares = match fanout.shares_root {
+        Some(root) => {
+            assert_valid_proof(
+                &args.proof,
+                &root,
+                membership_leaf(&mint.key(), args.shares),
+            )?;
+            args.shares
+        }
+        None => fanout.shares_per_nft,
+    };
+    update_inflow_for_add(fanout, ctx.remaining_accounts, &ctx.accounts.rent)?;
+    start_mint_vouchers_for_add(
+        fanout,
+        &mint.key(),
+        ctx.remaining_accounts,
+        &ctx.accounts.rent,
+        &ctx.accounts.system_program,
+        &ctx.accounts.holder.to_account_info(),
+    )?;
+    update_fanout_for_add(fanout, shares)?;
+    membership_account.membership_key = mint.key();
+    membership_account.shares = shares;
+    membership_account.bump_seed = *ctx.bumps.get("membership_account").unwrap();
+    membership_account.version = ACCOUNT_VERSION;
+    membership_account.fanout = fanout.key();
+    update_voucher_for_add(fanout, membership_account);
+    emit!(MemberAdded {
+        fanout: fanout.key(),
+        membership_key: membership_account.membership_key,
+        membership_voucher: membership_account.key(),
+        shares,
+    });
+    Ok(())
+}
@@ -1,6 +1,10 @@
 use super::arg::AddMemberArgs;
-use crate::state::{Fanout, FanoutMembershipVoucher, FANOUT_MEMBERSHIP_VOUCHER_SIZE};
+use crate::events::MemberAdded;
+use crate::state::{
+    Fanout, FanoutMembershipVoucher, ACCOUNT_VERSION, FANOUT_MEMBERSHIP_VOUCHER_SIZE,
+};
 use crate::utils::logic::calculation::*;
+use crate::utils::start_mint_vouchers_for_add;
 use crate::utils::validation::{assert_membership_model, assert_owned_by, assert_owned_by_one};
 use crate::MembershipModel;
 use anchor_lang::prelude::*;
@@ -15,7 +19,7 @@ pub struct AddMemberWallet<'info> {
     pub member: UncheckedAccount<'info>,
     #[account(
     mut,
-    seeds = [b"fanout-config", fanout.name.as_bytes()],
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
     has_one = authority,
     bump = fanout.bump_seed,
     )]
@@ -33,10 +37,22 @@ pub struct AddMemberWallet<'info> {
     pub token_program: Program<'info, Token>,
 }
 
-pub fn add_member_wallet(ctx: Context<AddMemberWallet>, args: AddMemberArgs) -> Result<()> {
+pub fn add_member_wallet<'info>(
+    ctx: Context<'_, '_, '_, 'info, AddMemberWallet<'info>>,
+    args: AddMemberArgs,
+) -> Result<()> {
     let fanout = &mut ctx.accounts.fanout;
     let member = &ctx.accounts.member;
     let membership_account = &mut ctx.accounts.membership_account;
+    update_inflow_for_add(fanout, ctx.remaining_accounts, &ctx.accounts.rent)?;
+    start_mint_vouchers_for_add(
+        fanout,
+        &member.key(),
+        ctx.remaining_accounts,
+        &ctx.accounts.rent,
+        &ctx.accounts.system_program,
+        &ctx.accounts.authority.to_account_info(),
+    )?;
     update_fanout_for_add(fanout, args.shares)?;
     assert_membership_model(fanout, MembershipModel::Wallet)?;
     assert_owned_by(&fanout.to_account_info(), &crate::ID)?;
@@ -44,6 +60,15 @@ pub fn add_member_wallet(ctx: Context<AddMemberWallet>, args: AddMemberArgs) ->
     membership_account.membership_key = member.key();
     membership_account.shares = args.shares;
     membership_account.bump_seed = *ctx.bumps.get("membership_account").unwrap();
+    membership_account.version = ACCOUNT_VERSION;
     membership_account.fanout = fanout.key();
+    membership_account.rent_payer = Some(ctx.accounts.authority.key());
+    update_voucher_for_add(fanout, membership_account);
+    emit!(MemberAdded {
+        fanout: fanout.key(),
+        membership_key: membership_account.membership_key,
+        membership_voucher: membership_account.key(),
+        shares: membership_account.shares,
+    });
     Ok(())
 }
@@ -1,3 +1,6 @@
+pub mod native_mint;
 pub mod nft_member;
+pub mod reserve;
 pub mod token_member;
+pub mod treasury;
 pub mod wallet_member;
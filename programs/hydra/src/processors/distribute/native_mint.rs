@@ -0,0 +1,55 @@
+use crate::error::HydraError;
+use crate::events::NativeMintUnwrapped;
+use crate::state::Fanout;
+use crate::utils::logic::transfer::unwrap_native_mint_holding;
+use crate::utils::validation::assert_owned_by;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct UnwrapNativeMint<'info> {
+    pub payer: Signer<'info>,
+    #[account(
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+    #[account(
+    mut,
+    constraint = fanout.account_key == holding_account.key(),
+    seeds = [b"fanout-native-account", fanout.key().as_ref()],
+    bump
+    )]
+    /// CHECK: Native Account
+    pub holding_account: UncheckedAccount<'info>,
+    #[account(
+    mut,
+    constraint = native_mint_holding_account.owner == fanout.key(),
+    constraint = native_mint_holding_account.mint == spl_token::native_mint::id(),
+    constraint = native_mint_holding_account.close_authority.is_none(),
+    )]
+    pub native_mint_holding_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+// The closed account's lamports, rent included, are picked up by the next update_inflow.
+pub fn unwrap_native_mint(ctx: Context<UnwrapNativeMint>) -> Result<()> {
+    let fanout = &ctx.accounts.fanout;
+    assert_owned_by(&fanout.to_account_info(), &crate::ID)?;
+    if !fanout.unwrap_native_mint {
+        return Err(HydraError::NativeMintUnwrapDisabled.into());
+    }
+    let amount = ctx.accounts.native_mint_holding_account.amount;
+    unwrap_native_mint_holding(
+        fanout,
+        fanout.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.native_mint_holding_account.to_account_info(),
+        ctx.accounts.holding_account.to_account_info(),
+    )?;
+    emit!(NativeMintUnwrapped {
+        fanout: fanout.key(),
+        amount,
+    });
+    Ok(())
+}
@@ -10,6 +10,7 @@ use anchor_spl::token::{Mint, Token, TokenAccount};
 #[derive(Accounts)]
 #[instruction(distribute_for_mint: bool)]
 pub struct DistributeNftMember<'info> {
+    #[account(mut)]
     pub payer: Signer<'info>,
     #[account(mut)]
     /// CHECK: Checked in program
@@ -32,7 +33,7 @@ pub struct DistributeNftMember<'info> {
     pub membership_voucher: Box<Account<'info, FanoutMembershipVoucher>>,
     #[account(
     mut,
-    seeds = [b"fanout-config", fanout.name.as_bytes()],
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
     bump = fanout.bump_seed,
     )]
     pub fanout: Account<'info, Fanout>,
@@ -54,8 +55,8 @@ pub struct DistributeNftMember<'info> {
     pub token_program: Program<'info, Token>,
 }
 
-pub fn distribute_for_nft(
-    ctx: Context<DistributeNftMember>,
+pub fn distribute_for_nft<'info>(
+    ctx: Context<'_, '_, '_, 'info, DistributeNftMember<'info>>,
     distribute_for_mint: bool,
 ) -> Result<()> {
     let fanout = &mut ctx.accounts.fanout;
@@ -90,6 +91,8 @@ pub fn distribute_for_nft(
             ctx.accounts.payer.to_account_info(),
             ctx.accounts.member.to_owned(),
             &ctx.accounts.membership_key.key(),
+            &ctx.accounts.membership_mint_token_account.owner,
+            ctx.remaining_accounts,
         )?;
     } else {
         distribute_native(
@@ -97,7 +100,10 @@ pub fn distribute_for_nft(
             &mut ctx.accounts.fanout,
             &mut ctx.accounts.membership_voucher,
             ctx.accounts.member.to_owned(),
+            ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.membership_mint_token_account.owner,
             ctx.accounts.rent.to_owned(),
+            ctx.remaining_accounts,
         )?;
     }
     Ok(())
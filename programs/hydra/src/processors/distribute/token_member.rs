@@ -2,7 +2,8 @@ use crate::error::HydraError;
 
 use crate::state::{Fanout, FanoutMembershipVoucher, MembershipModel};
 
-use crate::utils::logic::distribution::{distribute_mint, distribute_native};
+use crate::utils::logic::calculation::update_membership_supply;
+use crate::utils::logic::distribution::{distribute_mint, distribute_native, payout_recipient};
 
 use crate::utils::validation::*;
 
@@ -12,6 +13,7 @@ use anchor_spl::token::{Mint, Token, TokenAccount};
 #[derive(Accounts)]
 #[instruction(distribute_for_mint: bool)]
 pub struct DistributeTokenMember<'info> {
+    #[account(mut)]
     pub payer: Signer<'info>,
     #[account(mut)]
     /// CHECK: Optional Account
@@ -29,12 +31,13 @@ pub struct DistributeTokenMember<'info> {
     mut,
     seeds = [b"fanout-membership", fanout.key().as_ref(), member.key().as_ref()],
     constraint = membership_voucher.membership_key == member.key(),
+    constraint = membership_voucher.shares > 0,
     bump = membership_voucher.bump_seed,
     )]
     pub membership_voucher: Box<Account<'info, FanoutMembershipVoucher>>,
     #[account(
     mut,
-    seeds = [b"fanout-config", fanout.name.as_bytes()],
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
     bump = fanout.bump_seed,
     )]
     pub fanout: Box<Account<'info, Fanout>>,
@@ -63,13 +66,12 @@ pub struct DistributeTokenMember<'info> {
     mut,
     constraint = member_stake_account.owner == membership_voucher.key(),
     constraint = member_stake_account.mint == membership_mint.key(),
-    constraint = member_stake_account.amount > 0
     )]
     pub member_stake_account: Account<'info, TokenAccount>,
 }
 
-pub fn distribute_for_token(
-    ctx: Context<DistributeTokenMember>,
+pub fn distribute_for_token<'info>(
+    ctx: Context<'_, '_, '_, 'info, DistributeTokenMember<'info>>,
     distribute_for_mint: bool,
 ) -> Result<()> {
     let fanout = &mut ctx.accounts.fanout;
@@ -78,7 +80,7 @@ pub fn distribute_for_token(
     let membership_voucher_info = membership_voucher.to_account_info();
     let member = &mut ctx.accounts.member;
     let membership_mint = &ctx.accounts.membership_mint;
-    fanout.total_shares = membership_mint.supply;
+    update_membership_supply(fanout, membership_mint.supply);
     assert_ata(
         &ctx.accounts.member_stake_account.to_account_info(),
         &membership_voucher.key(),
@@ -90,6 +92,11 @@ pub fn distribute_for_token(
     assert_owned_by(&member.to_account_info(), &System::id())?;
     assert_membership_model(fanout, MembershipModel::Token)?;
     assert_shares_distributed(fanout)?;
+    let recipient = payout_recipient(
+        &ctx.accounts.membership_voucher,
+        ctx.accounts.member.to_owned(),
+        ctx.remaining_accounts,
+    )?;
 
     if distribute_for_mint {
         distribute_mint(
@@ -104,16 +111,21 @@ pub fn distribute_for_token(
             ctx.accounts.system_program.to_owned(),
             ctx.accounts.token_program.to_owned(),
             ctx.accounts.payer.to_account_info(),
-            ctx.accounts.member.to_owned(),
+            recipient,
+            &ctx.accounts.member.key(),
             &ctx.accounts.member.key(),
+            ctx.remaining_accounts,
         )?;
     } else {
         distribute_native(
             &mut ctx.accounts.holding_account,
             &mut ctx.accounts.fanout,
             &mut ctx.accounts.membership_voucher,
-            ctx.accounts.member.to_owned(),
+            recipient,
+            ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.member.key(),
             ctx.accounts.rent.to_owned(),
+            ctx.remaining_accounts,
         )?;
     }
     Ok(())
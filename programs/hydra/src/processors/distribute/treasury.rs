@@ -0,0 +1,60 @@
+use crate::state::Fanout;
+use crate::utils::logic::distribution::{distribute_held_mint, distribute_held_native, HeldInflow};
+use crate::utils::validation::assert_owned_by;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+
+#[derive(Accounts)]
+#[instruction(distribute_for_mint: bool)]
+pub struct DistributeTreasury<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+    mut,
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    has_one = authority,
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+    #[account(mut)]
+    /// CHECK: Could be native or Token Account
+    pub holding_account: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Optional Account
+    pub fanout_for_mint: UncheckedAccount<'info>,
+    pub fanout_mint: Account<'info, Mint>,
+    #[account(mut)]
+    /// CHECK: Optional Account
+    pub fanout_mint_authority_token_account: UncheckedAccount<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub token_program: Program<'info, Token>,
+}
+
+// The treasury holds unstaked inflow of token fanouts and inflow of unallocated mint shares.
+pub fn distribute_for_treasury(
+    ctx: Context<DistributeTreasury>,
+    distribute_for_mint: bool,
+) -> Result<()> {
+    let fanout = &ctx.accounts.fanout;
+    assert_owned_by(&fanout.to_account_info(), &crate::ID)?;
+    if distribute_for_mint {
+        distribute_held_mint(
+            ctx.accounts.fanout_mint.to_owned(),
+            &mut ctx.accounts.fanout_for_mint,
+            &mut ctx.accounts.fanout_mint_authority_token_account,
+            &mut ctx.accounts.holding_account,
+            &mut ctx.accounts.fanout,
+            ctx.accounts.token_program.to_owned(),
+            &ctx.accounts.authority.key(),
+            HeldInflow::Treasury,
+        )
+    } else {
+        distribute_held_native(
+            &mut ctx.accounts.holding_account,
+            &mut ctx.accounts.fanout,
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.rent.to_owned(),
+            HeldInflow::Treasury,
+        )
+    }
+}
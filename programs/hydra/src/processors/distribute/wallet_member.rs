@@ -1,15 +1,17 @@
+use crate::error::HydraError;
 use crate::MembershipModel;
 use anchor_lang::prelude::*;
 
 use crate::state::{Fanout, FanoutMembershipVoucher};
 use crate::utils::validation::*;
 
-use crate::utils::logic::distribution::{distribute_mint, distribute_native};
+use crate::utils::logic::distribution::{distribute_mint, distribute_native, payout_recipient};
 use anchor_spl::token::{Mint, Token};
 
 #[derive(Accounts)]
 #[instruction(distribute_for_mint: bool)]
 pub struct DistributeWalletMember<'info> {
+    #[account(mut)]
     pub payer: Signer<'info>,
     #[account(mut)]
     /// CHECK: Optional Account
@@ -23,7 +25,7 @@ pub struct DistributeWalletMember<'info> {
     pub membership_voucher: Box<Account<'info, FanoutMembershipVoucher>>,
     #[account(
     mut,
-    seeds = [b"fanout-config", fanout.name.as_bytes()],
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
     bump = fanout.bump_seed,
     )]
     pub fanout: Account<'info, Fanout>,
@@ -45,8 +47,8 @@ pub struct DistributeWalletMember<'info> {
     pub token_program: Program<'info, Token>,
 }
 
-pub fn distribute_for_wallet(
-    ctx: Context<DistributeWalletMember>,
+pub fn distribute_for_wallet<'info>(
+    ctx: Context<'_, '_, '_, 'info, DistributeWalletMember<'info>>,
     distribute_for_mint: bool,
 ) -> Result<()> {
     let fanout = &mut ctx.accounts.fanout;
@@ -57,11 +59,19 @@ pub fn distribute_for_wallet(
     assert_owned_by(&fanout_info, &crate::ID)?;
     assert_owned_by(&membership_voucher_info, &crate::ID)?;
     assert_owned_by(&member.to_account_info(), &System::id())?;
-    assert_membership_model(fanout, MembershipModel::Wallet)?;
+    if fanout.membership_model != MembershipModel::Wallet
+        && fanout.membership_model != MembershipModel::Merkle
+    {
+        return Err(HydraError::InvalidMembershipModel.into());
+    }
     assert_shares_distributed(fanout)?;
+    let recipient = payout_recipient(
+        &ctx.accounts.membership_voucher,
+        ctx.accounts.member.to_owned(),
+        ctx.remaining_accounts,
+    )?;
     if distribute_for_mint {
         let membership_key = &ctx.accounts.member.key().clone();
-        let member = ctx.accounts.member.to_owned();
         distribute_mint(
             ctx.accounts.fanout_mint.to_owned(),
             &mut ctx.accounts.fanout_for_mint,
@@ -74,16 +84,21 @@ pub fn distribute_for_wallet(
             ctx.accounts.system_program.to_owned(),
             ctx.accounts.token_program.to_owned(),
             ctx.accounts.payer.to_account_info(),
-            member,
+            recipient,
             membership_key,
+            membership_key,
+            ctx.remaining_accounts,
         )?;
     } else {
         distribute_native(
             &mut ctx.accounts.holding_account,
             &mut ctx.accounts.fanout,
             &mut ctx.accounts.membership_voucher,
-            ctx.accounts.member.to_owned(),
+            recipient,
+            ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.member.key(),
             ctx.accounts.rent.to_owned(),
+            ctx.remaining_accounts,
         )?;
     }
     Ok(())
@@ -0,0 +1,65 @@
+use crate::error::HydraError;
+use crate::events::MembershipMintRegistered;
+use crate::state::{
+    Fanout, FanoutMembershipMint, MembershipModel, ACCOUNT_VERSION, FANOUT_MEMBERSHIP_MINT_SIZE,
+};
+use crate::utils::logic::calculation::update_weighted_mint_supply;
+use crate::utils::validation::assert_membership_model;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+#[derive(Accounts)]
+#[instruction(weight: u64)]
+pub struct InitializeFanoutForMembershipMint<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+    mut,
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    has_one = authority,
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+    #[account(
+    init,
+    payer = authority,
+    space = FANOUT_MEMBERSHIP_MINT_SIZE,
+    seeds = [b"fanout-membership-mint", fanout.key().as_ref(), membership_mint.key().as_ref()],
+    bump
+    )]
+    pub fanout_membership_mint: Account<'info, FanoutMembershipMint>,
+    #[account(
+    constraint = fanout.membership_mint != Some(membership_mint.key()) @ HydraError::InvalidMembershipMint,
+    )]
+    pub membership_mint: Account<'info, Mint>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn init_for_membership_mint(
+    ctx: Context<InitializeFanoutForMembershipMint>,
+    weight: u64,
+) -> Result<()> {
+    let fanout = &mut ctx.accounts.fanout;
+    let fanout_membership_mint = &mut ctx.accounts.fanout_membership_mint;
+    assert_membership_model(fanout, MembershipModel::Token)?;
+    if weight == 0 {
+        return Err(HydraError::InvalidWeight.into());
+    }
+    fanout_membership_mint.fanout = fanout.key();
+    fanout_membership_mint.mint = ctx.accounts.membership_mint.key();
+    fanout_membership_mint.weight = weight;
+    fanout_membership_mint.bump_seed = *ctx.bumps.get("fanout_membership_mint").unwrap();
+    fanout_membership_mint.version = ACCOUNT_VERSION;
+    update_weighted_mint_supply(
+        fanout,
+        fanout_membership_mint,
+        ctx.accounts.membership_mint.supply,
+    )?;
+    emit!(MembershipMintRegistered {
+        fanout: fanout.key(),
+        mint: fanout_membership_mint.mint,
+        weight,
+    });
+    Ok(())
+}
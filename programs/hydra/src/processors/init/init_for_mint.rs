@@ -1,5 +1,6 @@
-use crate::error::HydraError;
-use crate::state::{Fanout, FanoutMint};
+use crate::error::{HydraError, OrArithError};
+use crate::events::MintRegistered;
+use crate::state::{Fanout, FanoutMint, ACCOUNT_VERSION, FANOUT_MINT_SIZE};
 use crate::utils::validation::assert_ata;
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, TokenAccount};
@@ -11,7 +12,7 @@ pub struct InitializeFanoutForMint<'info> {
     pub authority: Signer<'info>,
     #[account(
     mut,
-    seeds = [b"fanout-config", fanout.name.as_bytes()],
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
     has_one = authority,
     bump = fanout.bump_seed,
     )]
@@ -19,7 +20,7 @@ pub struct InitializeFanoutForMint<'info> {
     #[account(
     init,
     payer= authority,
-    space = 200,
+    space = FANOUT_MINT_SIZE,
     seeds = [b"fanout-config", fanout.key().as_ref(), mint.key().as_ref()],
     bump
     )]
@@ -40,13 +41,21 @@ pub struct InitializeFanoutForMint<'info> {
 
 pub fn init_for_mint(ctx: Context<InitializeFanoutForMint>, bump_seed: u8) -> Result<()> {
     let fanout_mint = &mut ctx.accounts.fanout_for_mint;
-    let fanout = &ctx.accounts.fanout;
+    let fanout = &mut ctx.accounts.fanout;
     let mint_holding_account = &ctx.accounts.mint_holding_account;
+    // wSOL is paid out as native inflow while unwrapping is enabled
+    if fanout.unwrap_native_mint && ctx.accounts.mint.key() == spl_token::native_mint::id() {
+        return Err(HydraError::NativeMintUnwrapped.into());
+    }
     fanout_mint.fanout = fanout.to_account_info().key();
     fanout_mint.total_inflow = mint_holding_account.amount;
     fanout_mint.last_snapshot_amount = mint_holding_account.amount;
     fanout_mint.bump_seed = bump_seed;
+    fanout_mint.version = ACCOUNT_VERSION;
     fanout_mint.mint = ctx.accounts.mint.to_account_info().key();
+    fanout_mint.counted = true;
+    fanout_mint.share_epoch = fanout.share_epoch;
+    fanout.total_mints = fanout.total_mints.checked_add(1).or_arith_error()?;
     assert_ata(
         &mint_holding_account.to_account_info(),
         &fanout.key(),
@@ -54,5 +63,11 @@ pub fn init_for_mint(ctx: Context<InitializeFanoutForMint>, bump_seed: u8) -> Re
         Some(HydraError::HoldingAccountMustBeAnATA.into()),
     )?;
     fanout_mint.token_account = mint_holding_account.to_account_info().key();
+    emit!(MintRegistered {
+        fanout: fanout.key(),
+        fanout_for_mint: fanout_mint.key(),
+        mint: fanout_mint.mint,
+        token_account: fanout_mint.token_account,
+    });
     Ok(())
 }
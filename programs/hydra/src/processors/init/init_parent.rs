@@ -1,5 +1,7 @@
 use crate::error::HydraError;
-use crate::state::{Fanout, MembershipModel};
+use crate::events::FanoutInitialized;
+use crate::processors::update_fanout::metadata::write_fanout_metadata;
+use crate::state::{Fanout, MembershipModel, ACCOUNT_VERSION, FANOUT_SIZE};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token};
 
@@ -9,6 +11,13 @@ pub struct InitializeFanoutArgs {
     pub native_account_bump_seed: u8,
     pub name: String,
     pub total_shares: u64,
+    pub metadata: Option<FanoutMetadataArgs>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct FanoutMetadataArgs {
+    pub uri: String,
+    pub description: String,
 }
 
 #[derive(Accounts)]
@@ -18,7 +27,7 @@ pub struct InitializeFanout<'info> {
     pub authority: Signer<'info>,
     #[account(
     init,
-    space = 300,
+    space = FANOUT_SIZE,
     seeds = [b"fanout-config", args.name.as_bytes()],
     bump,
     payer = authority
@@ -40,21 +49,78 @@ pub struct InitializeFanout<'info> {
     pub rent: Sysvar<'info, Rent>,
     pub token_program: Program<'info, Token>,
 }
-pub fn init(
-    ctx: Context<InitializeFanout>,
-    args: InitializeFanoutArgs,
+// Metadata given at init is written to the fanout metadata account, passed first in the
+// remaining accounts.
+pub fn init<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitializeFanout<'info>>,
+    mut args: InitializeFanoutArgs,
     model: MembershipModel,
 ) -> Result<()> {
-    let membership_mint = &ctx.accounts.membership_mint;
     let fanout = &mut ctx.accounts.fanout;
-    fanout.authority = ctx.accounts.authority.to_account_info().key();
+    let metadata = args.metadata.take();
     fanout.account_key = ctx.accounts.holding_account.to_account_info().key();
+    init_fanout(
+        fanout,
+        &ctx.accounts.authority,
+        &ctx.accounts.membership_mint,
+        &ctx.accounts.rent,
+        &ctx.accounts.token_program,
+        args,
+        model,
+    )?;
+    init_fanout_metadata(
+        &fanout.key(),
+        metadata,
+        ctx.remaining_accounts,
+        &ctx.accounts.authority,
+        &ctx.accounts.rent,
+        &ctx.accounts.system_program,
+    )
+}
+
+pub fn init_fanout_metadata<'info>(
+    fanout: &Pubkey,
+    metadata: Option<FanoutMetadataArgs>,
+    remaining_accounts: &[AccountInfo<'info>],
+    authority: &Signer<'info>,
+    rent: &Sysvar<'info, Rent>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let metadata = match metadata {
+        Some(metadata) => metadata,
+        None => return Ok(()),
+    };
+    let metadata_info = remaining_accounts
+        .first()
+        .ok_or(HydraError::InvalidFanoutMetadata)?;
+    write_fanout_metadata(
+        fanout,
+        metadata_info,
+        &authority.to_account_info(),
+        rent,
+        system_program,
+        metadata.uri,
+        metadata.description,
+    )
+}
+
+pub fn init_fanout<'info>(
+    fanout: &mut Account<'info, Fanout>,
+    authority: &Signer<'info>,
+    membership_mint: &Account<'info, Mint>,
+    rent: &Sysvar<'info, Rent>,
+    token_program: &Program<'info, Token>,
+    args: InitializeFanoutArgs,
+    model: MembershipModel,
+) -> Result<()> {
+    fanout.authority = authority.to_account_info().key();
     fanout.name = args.name;
     fanout.total_shares = args.total_shares;
     fanout.total_available_shares = args.total_shares;
     fanout.total_inflow = 0;
     fanout.last_snapshot_amount = fanout.total_inflow;
     fanout.bump_seed = args.bump_seed;
+    fanout.version = ACCOUNT_VERSION;
     fanout.membership_model = model;
     fanout.membership_mint = if membership_mint.key() == spl_token::native_mint::id() {
         None
@@ -62,34 +128,42 @@ pub fn init(
         Some(membership_mint.key())
     };
     match fanout.membership_model {
-        MembershipModel::Wallet | MembershipModel::NFT => {
+        MembershipModel::Wallet | MembershipModel::NFT | MembershipModel::Merkle => {
             fanout.membership_mint = None;
             fanout.total_staked_shares = None;
         }
         MembershipModel::Token => {
             fanout.total_shares = membership_mint.supply;
+            fanout.membership_supply = membership_mint.supply;
             fanout.total_available_shares = 0;
             if fanout.membership_mint.is_none() {
                 return Err(HydraError::MintAccountRequired.into());
             }
-            let mint = &ctx.accounts.membership_mint;
+            let mint = membership_mint;
             fanout.total_staked_shares = Some(0);
             if !mint.is_initialized {
-                let cpi_program = ctx.accounts.token_program.to_account_info();
+                let cpi_program = token_program.to_account_info();
                 let accounts = anchor_spl::token::InitializeMint {
                     mint: mint.to_account_info(),
-                    rent: ctx.accounts.rent.to_account_info(),
+                    rent: rent.to_account_info(),
                 };
                 let cpi_ctx = CpiContext::new(cpi_program, accounts);
                 anchor_spl::token::initialize_mint(
                     cpi_ctx,
                     0,
-                    &ctx.accounts.authority.to_account_info().key(),
-                    Some(&ctx.accounts.authority.to_account_info().key()),
+                    &authority.to_account_info().key(),
+                    Some(&authority.to_account_info().key()),
                 )?;
             }
         }
     };
-
+    emit!(FanoutInitialized {
+        fanout: fanout.key(),
+        authority: fanout.authority,
+        name: fanout.name.clone(),
+        membership_model: fanout.membership_model,
+        membership_mint: fanout.membership_mint,
+        total_shares: fanout.total_shares,
+    });
     Ok(())
 }
@@ -0,0 +1,72 @@
+use crate::error::HydraError;
+use crate::processors::init::init_parent::{
+    init_fanout, init_fanout_metadata, InitializeFanoutArgs,
+};
+use crate::state::{Fanout, MembershipModel, FANOUT_SIZE};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+
+#[derive(Accounts)]
+#[instruction(args: InitializeFanoutArgs)]
+pub struct InitializeScopedFanout<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub base: Signer<'info>,
+    #[account(
+    init,
+    space = FANOUT_SIZE,
+    seeds = [b"fanout-config", base.key().as_ref(), args.name.as_bytes()],
+    bump,
+    payer = authority
+    )]
+    pub fanout: Account<'info, Fanout>,
+    #[account(
+    init,
+    space = 1,
+    seeds = [b"fanout-native-account", fanout.key().as_ref()],
+    bump,
+    payer = authority
+    )
+    ]
+    /// CHECK: Native Account
+    pub holding_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    #[account(mut)]
+    pub membership_mint: Account<'info, Mint>,
+    pub rent: Sysvar<'info, Rent>,
+    pub token_program: Program<'info, Token>,
+}
+
+// The base key can be the authority itself or a fresh keypair; either way only its holder
+// can create a fanout under it. Metadata is passed as for init.
+pub fn init_scoped<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitializeScopedFanout<'info>>,
+    mut args: InitializeFanoutArgs,
+    model: MembershipModel,
+) -> Result<()> {
+    // An empty name would derive the same address as a legacy fanout named by the base key
+    if args.name.is_empty() {
+        return Err(HydraError::InvalidName.into());
+    }
+    let fanout = &mut ctx.accounts.fanout;
+    let metadata = args.metadata.take();
+    fanout.account_key = ctx.accounts.holding_account.to_account_info().key();
+    fanout.base = Some(ctx.accounts.base.key());
+    init_fanout(
+        fanout,
+        &ctx.accounts.authority,
+        &ctx.accounts.membership_mint,
+        &ctx.accounts.rent,
+        &ctx.accounts.token_program,
+        args,
+        model,
+    )?;
+    init_fanout_metadata(
+        &fanout.key(),
+        metadata,
+        ctx.remaining_accounts,
+        &ctx.accounts.authority,
+        &ctx.accounts.rent,
+        &ctx.accounts.system_program,
+    )
+}
@@ -1,2 +1,4 @@
+pub mod init_for_membership_mint;
 pub mod init_for_mint;
 pub mod init_parent;
+pub mod init_scoped;
@@ -0,0 +1,283 @@
+use crate::error::{HydraError, OrArithError};
+use crate::events::AccountMigrated;
+use crate::state::{
+    Fanout, FanoutMembershipMint, FanoutMembershipMintVoucher, FanoutMembershipVoucher,
+    FanoutMetadata, FanoutMint, ACCOUNT_VERSION, FANOUT_MEMBERSHIP_MINT_SIZE,
+    FANOUT_MEMBERSHIP_VOUCHER_SIZE, FANOUT_METADATA_SIZE, FANOUT_MINT_MEMBERSHIP_VOUCHER_SIZE,
+    FANOUT_MINT_SIZE, FANOUT_SIZE,
+};
+use crate::utils::find_remaining_account;
+use crate::utils::validation::assert_owned_by;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::system_instruction;
+use anchor_lang::Discriminator;
+use std::convert::TryInto;
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, owner = crate::ID)]
+    /// CHECK: Checked by discriminator in program
+    pub account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// Only accounts smaller than the current layout need migrating. They are grown before
+// deserializing, so fields added since they were created read as their zero defaults.
+// A FanoutMint is counted on its fanout as it is migrated, so the fanout, migrated first, is
+// passed in the remaining accounts.
+pub fn migrate_account<'info>(
+    ctx: Context<'_, '_, '_, 'info, MigrateAccount<'info>>,
+) -> Result<()> {
+    let account_info = ctx.accounts.account.to_account_info();
+    let discriminator: [u8; 8] = match account_info.try_borrow_data()?.get(0..8) {
+        Some(discriminator) => discriminator.try_into().unwrap(),
+        None => return Err(HydraError::InvalidAccountType.into()),
+    };
+    let size = if discriminator == Fanout::discriminator() {
+        FANOUT_SIZE
+    } else if discriminator == FanoutMint::discriminator() {
+        FANOUT_MINT_SIZE
+    } else if discriminator == FanoutMembershipVoucher::discriminator() {
+        FANOUT_MEMBERSHIP_VOUCHER_SIZE
+    } else if discriminator == FanoutMembershipMintVoucher::discriminator() {
+        FANOUT_MINT_MEMBERSHIP_VOUCHER_SIZE
+    } else if discriminator == FanoutMembershipMint::discriminator() {
+        FANOUT_MEMBERSHIP_MINT_SIZE
+    } else if discriminator == FanoutMetadata::discriminator() {
+        FANOUT_METADATA_SIZE
+    } else {
+        return Err(HydraError::InvalidAccountType.into());
+    };
+    if account_info.data_len() >= size {
+        return Err(HydraError::AccountUpToDate.into());
+    }
+    let required_lamports = Rent::get()?
+        .minimum_balance(size)
+        .saturating_sub(account_info.lamports());
+    if required_lamports > 0 {
+        invoke(
+            &system_instruction::transfer(
+                ctx.accounts.payer.key,
+                account_info.key,
+                required_lamports,
+            ),
+            &[
+                ctx.accounts.payer.to_account_info(),
+                account_info.clone(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+    }
+    account_info.realloc(size, true)?;
+    if discriminator == Fanout::discriminator() {
+        set_version::<Fanout>(&account_info, |a| &mut a.version)?;
+    } else if discriminator == FanoutMint::discriminator() {
+        set_version::<FanoutMint>(&account_info, |a| &mut a.version)?;
+        count_fanout_mint(&account_info, ctx.remaining_accounts)?;
+    } else if discriminator == FanoutMembershipVoucher::discriminator() {
+        set_version::<FanoutMembershipVoucher>(&account_info, |a| &mut a.version)?;
+    } else if discriminator == FanoutMembershipMintVoucher::discriminator() {
+        set_version::<FanoutMembershipMintVoucher>(&account_info, |a| &mut a.version)?;
+    } else if discriminator == FanoutMembershipMint::discriminator() {
+        set_version::<FanoutMembershipMint>(&account_info, |a| &mut a.version)?;
+    } else {
+        set_version::<FanoutMetadata>(&account_info, |a| &mut a.version)?;
+    }
+    emit!(AccountMigrated {
+        account: account_info.key(),
+        version: ACCOUNT_VERSION,
+    });
+    Ok(())
+}
+
+fn set_version<T: AccountSerialize + AccountDeserialize>(
+    account_info: &AccountInfo,
+    version: impl FnOnce(&mut T) -> &mut u8,
+) -> Result<()> {
+    let mut account = {
+        let mut data: &[u8] = &account_info.try_borrow_data()?;
+        T::try_deserialize(&mut data)?
+    };
+    *version(&mut account) = ACCOUNT_VERSION;
+    let mut data: &mut [u8] = &mut account_info.try_borrow_mut_data()?;
+    account.try_serialize(&mut data)
+}
+
+// Instructions that must see every mint of a fanout compare the mints passed with the fanout's
+// count, so a mint registered before mints were counted must be counted before it can be used.
+fn count_fanout_mint(account_info: &AccountInfo, remaining_accounts: &[AccountInfo]) -> Result<()> {
+    let mut fanout_mint = {
+        let mut data: &[u8] = &account_info.try_borrow_data()?;
+        FanoutMint::try_deserialize(&mut data)?
+    };
+    if fanout_mint.counted {
+        return Ok(());
+    }
+    let fanout_info = find_remaining_account(remaining_accounts, &fanout_mint.fanout)
+        .ok_or(HydraError::FanoutRequired)?;
+    assert_owned_by(&fanout_info, &crate::ID)?;
+    let mut fanout = {
+        let mut data: &[u8] = &fanout_info.try_borrow_data()?;
+        Fanout::try_deserialize(&mut data)?
+    };
+    fanout.total_mints = fanout.total_mints.checked_add(1).or_arith_error()?;
+    fanout_mint.counted = true;
+    let mut fanout_data: &mut [u8] = &mut fanout_info.try_borrow_mut_data()?;
+    fanout.try_serialize(&mut fanout_data)?;
+    let mut data: &mut [u8] = &mut account_info.try_borrow_mut_data()?;
+    fanout_mint.try_serialize(&mut data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::logic::calculation::tests::set_clock;
+    use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+    use anchor_lang::solana_program::system_program;
+    use std::collections::BTreeMap;
+
+    const LAMPORTS: u64 = 1_000_000_000;
+    const LEGACY_FANOUT_SIZE: usize = 300;
+    const LEGACY_FANOUT_MINT_SIZE: usize = 200;
+
+    // Laid out as the runtime serializes it: the data length in the eight bytes before the
+    // data, which realloc rewrites, and room after it to grow into. The spare room is filled
+    // with stale bytes so fields added since the account was created only read as zero if
+    // migrating clears them.
+    fn account(key: Pubkey, owner: Pubkey, data: Vec<u8>) -> AccountInfo<'static> {
+        let len = data.len();
+        let mut buffer = (len as u64).to_le_bytes().to_vec();
+        buffer.extend(data);
+        buffer.resize(8 + len + MAX_PERMITTED_DATA_INCREASE, u8::MAX);
+        let buffer = Box::leak(buffer.into_boxed_slice());
+        AccountInfo::new(
+            Box::leak(Box::new(key)),
+            false,
+            true,
+            Box::leak(Box::new(LAMPORTS)),
+            &mut buffer[8..8 + len],
+            Box::leak(Box::new(owner)),
+            false,
+            0,
+        )
+    }
+
+    // Fields added since serialize as zeros, so an account created before them is the current
+    // layout cut to the size it was created with.
+    fn program_account<T: AccountSerialize>(
+        key: Pubkey,
+        value: &T,
+        size: usize,
+    ) -> AccountInfo<'static> {
+        let mut data = Vec::new();
+        value.try_serialize(&mut data).unwrap();
+        data.resize(size, 0);
+        account(key, crate::ID, data)
+    }
+
+    fn read<T: AccountDeserialize>(account_info: &AccountInfo) -> T {
+        let mut data: &[u8] = &account_info.try_borrow_data().unwrap();
+        T::try_deserialize(&mut data).unwrap()
+    }
+
+    fn migrate(
+        account_info: &AccountInfo<'static>,
+        remaining_accounts: &[AccountInfo<'static>],
+    ) -> Result<()> {
+        let mut payer = account(Pubkey::new_unique(), system_program::ID, vec![]);
+        payer.is_signer = true;
+        let mut system = account(system_program::ID, Pubkey::default(), vec![]);
+        system.executable = true;
+        let mut accounts = MigrateAccount {
+            payer: Signer::try_from(&payer)?,
+            account: UncheckedAccount::try_from(account_info.clone()),
+            system_program: Program::try_from(&system)?,
+        };
+        migrate_account(Context::new(
+            &crate::ID,
+            &mut accounts,
+            remaining_accounts,
+            BTreeMap::new(),
+        ))
+    }
+
+    #[test]
+    fn test_migrate_grows_legacy_fanout() {
+        let _sysvars = set_clock(0, 0);
+        let authority = Pubkey::new_unique();
+        let fanout = program_account(
+            Pubkey::new_unique(),
+            &Fanout {
+                authority,
+                name: "legacy".to_string(),
+                total_shares: 100,
+                total_members: 2,
+                total_inflow: 5000,
+                ..Default::default()
+            },
+            LEGACY_FANOUT_SIZE,
+        );
+
+        migrate(&fanout, &[]).unwrap();
+
+        assert_eq!(fanout.data_len(), FANOUT_SIZE);
+        let migrated: Fanout = read(&fanout);
+        assert_eq!(migrated.authority, authority);
+        assert_eq!(migrated.name, "legacy");
+        assert_eq!(migrated.total_shares, 100);
+        assert_eq!(migrated.total_members, 2);
+        assert_eq!(migrated.total_inflow, 5000);
+        assert_eq!(migrated.version, ACCOUNT_VERSION);
+        assert_eq!(migrated.distribution_window, None);
+        assert_eq!(migrated.total_mints, 0);
+        assert_eq!(migrated.expiring_shares, 0);
+    }
+
+    #[test]
+    fn test_migrate_counts_legacy_fanout_mint() {
+        let _sysvars = set_clock(0, 0);
+        let fanout_key = Pubkey::new_unique();
+        let fanout = program_account(fanout_key, &Fanout::default(), FANOUT_SIZE);
+        let legacy_fanout_mint = || {
+            program_account(
+                Pubkey::new_unique(),
+                &FanoutMint {
+                    fanout: fanout_key,
+                    total_inflow: 700,
+                    ..Default::default()
+                },
+                LEGACY_FANOUT_MINT_SIZE,
+            )
+        };
+
+        assert_eq!(
+            migrate(&legacy_fanout_mint(), &[]).unwrap_err().to_string(),
+            Error::from(HydraError::FanoutRequired).to_string()
+        );
+        let fanout_mint = legacy_fanout_mint();
+        migrate(&fanout_mint, std::slice::from_ref(&fanout)).unwrap();
+
+        assert_eq!(fanout_mint.data_len(), FANOUT_MINT_SIZE);
+        let migrated: FanoutMint = read(&fanout_mint);
+        assert_eq!(migrated.fanout, fanout_key);
+        assert_eq!(migrated.total_inflow, 700);
+        assert_eq!(migrated.version, ACCOUNT_VERSION);
+        assert!(migrated.counted);
+        assert_eq!(migrated.last_distribution_window, None);
+        assert_eq!(read::<Fanout>(&fanout).total_mints, 1);
+    }
+
+    #[test]
+    fn test_migrate_rejects_current_account() {
+        let _sysvars = set_clock(0, 0);
+        let fanout = program_account(Pubkey::new_unique(), &Fanout::default(), FANOUT_SIZE);
+
+        assert_eq!(
+            migrate(&fanout, &[]).unwrap_err().to_string(),
+            Error::from(HydraError::AccountUpToDate).to_string()
+        );
+    }
+}
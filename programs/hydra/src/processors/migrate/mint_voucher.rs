@@ -0,0 +1,63 @@
+use crate::error::{HydraError, OrArithError};
+use crate::events::MintVoucherMigrated;
+use crate::state::{Fanout, FanoutMembershipMintVoucher, FanoutMint};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(total_inflow: u64)]
+pub struct MigrateMintVoucher<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    has_one = authority,
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+    #[account(
+    seeds = [b"fanout-config", fanout.key().as_ref(), fanout_for_mint.mint.as_ref()],
+    has_one = fanout,
+    bump = fanout_for_mint.bump_seed,
+    )]
+    pub fanout_for_mint: Account<'info, FanoutMint>,
+    /// CHECK: Used for derivation only
+    pub membership_key: UncheckedAccount<'info>,
+    #[account(
+    mut,
+    seeds = [
+    b"fanout-membership",
+    fanout_for_mint.key().as_ref(),
+    membership_key.key().as_ref(),
+    fanout_for_mint.mint.as_ref()
+    ],
+    has_one = fanout,
+    bump = fanout_for_mint_membership_voucher.bump_seed,
+    )]
+    pub fanout_for_mint_membership_voucher: Account<'info, FanoutMembershipMintVoucher>,
+}
+
+// Mint vouchers created before lifetime tracking start counting from zero, this lets the
+// authority backfill what was paid out before the upgrade, once per voucher.
+pub fn migrate_mint_voucher(ctx: Context<MigrateMintVoucher>, total_inflow: u64) -> Result<()> {
+    let voucher = &mut ctx.accounts.fanout_for_mint_membership_voucher;
+    if voucher.total_inflow_migrated {
+        return Err(HydraError::AlreadyMigrated.into());
+    }
+    voucher.total_inflow = voucher
+        .total_inflow
+        .checked_add(total_inflow)
+        .or_arith_error()?;
+    // A member can't have been paid more than the mint inflow their voucher settled against
+    if voucher.total_inflow > voucher.last_inflow
+        || voucher.total_inflow > ctx.accounts.fanout_for_mint.total_inflow
+    {
+        return Err(HydraError::InvalidMigratedInflow.into());
+    }
+    voucher.total_inflow_migrated = true;
+    emit!(MintVoucherMigrated {
+        fanout: ctx.accounts.fanout.key(),
+        membership_key: ctx.accounts.membership_key.key(),
+        mint: voucher.fanout_mint,
+        total_inflow: voucher.total_inflow,
+    });
+    Ok(())
+}
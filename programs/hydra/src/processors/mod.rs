@@ -1,23 +1,59 @@
 pub mod add_member;
 pub mod distribute;
 pub mod init;
+pub mod migrate;
 pub mod remove_member;
 pub mod signing;
 pub mod stake;
 pub mod transfer_shares;
+pub mod update_fanout;
+pub mod update_member;
 
 pub use self::add_member::arg::*;
+pub use self::add_member::merkle::*;
 pub use self::add_member::nft::*;
+pub use self::add_member::nft_collection::*;
 pub use self::add_member::wallet::*;
+pub use self::distribute::native_mint::*;
 pub use self::distribute::nft_member::*;
+pub use self::distribute::reserve::*;
 pub use self::distribute::token_member::*;
+pub use self::distribute::treasury::*;
 pub use self::distribute::wallet_member::*;
+pub use self::init::init_for_membership_mint::*;
 pub use self::init::init_for_mint::*;
 pub use self::init::init_parent::*;
+pub use self::init::init_scoped::*;
+pub use self::migrate::account::*;
+pub use self::migrate::mint_voucher::*;
 pub use self::remove_member::remove_member::*;
 pub use self::signing::sign_metadata::*;
 pub use self::stake::set::*;
 pub use self::stake::set_for::*;
+pub use self::stake::set_weighted::*;
 pub use self::stake::unstake::*;
+pub use self::stake::unstake_weighted::*;
+pub use self::transfer_shares::member_transfer_shares::*;
 pub use self::transfer_shares::transfer_shares::*;
+pub use self::update_fanout::crank_reward::*;
+pub use self::update_fanout::distribution_window::*;
+pub use self::update_fanout::fee::*;
+pub use self::update_fanout::member_transfers::*;
+pub use self::update_fanout::membership_collection::*;
+pub use self::update_fanout::metadata::*;
+pub use self::update_fanout::min_distribution::*;
+pub use self::update_fanout::mint_min_distribution::*;
+pub use self::update_fanout::mint_share_table::*;
+pub use self::update_fanout::reserve::*;
+pub use self::update_fanout::resignation_policy::*;
+pub use self::update_fanout::shares_root::*;
+pub use self::update_fanout::unstaked_policy::*;
+pub use self::update_fanout::unwrap_native_mint::*;
 pub use self::remove_member::remove_member::*;
+pub use self::update_member::expiry::*;
+pub use self::update_member::label::*;
+pub use self::update_member::mint_shares::*;
+pub use self::update_member::payout_destination::*;
+pub use self::update_member::resign::*;
+pub use self::update_member::rotate_key::*;
+pub use self::update_member::share_schedule::*;
@@ -1,7 +1,8 @@
 use anchor_lang::AccountsClose;
+use crate::events::MemberRemoved;
 use crate::state::{Fanout, FanoutMembershipVoucher};
 use crate::utils::logic::calculation::*;
-use crate::utils::validation::{assert_membership_model, assert_owned_by};
+use crate::utils::validation::{assert_membership_model, assert_owned_by, assert_settled};
 use crate::MembershipModel;
 use anchor_lang::prelude::*;
 
@@ -15,7 +16,7 @@ pub struct RemoveMember<'info> {
     pub member: UncheckedAccount<'info>,
     #[account(
     mut,
-    seeds = [b"fanout-config", fanout.name.as_bytes()],
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
     has_one = authority,
     bump = fanout.bump_seed,
     )]
@@ -38,6 +39,7 @@ pub fn remove_member(ctx: Context<RemoveMember>) -> Result<()> {
     assert_membership_model(fanout, MembershipModel::Wallet)?;
     assert_owned_by(&fanout.to_account_info(), &crate::ID)?;
     assert_owned_by(&member_voucher.to_account_info(), &crate::ID)?;
+    assert_settled(fanout, member_voucher)?;
     update_fanout_for_remove(fanout)?;
     if assert_owned_by(&ctx.accounts.member, &spl_token::id()).is_ok() {
         return Err(HydraError::InvalidCloseAccountDestination.into());
@@ -50,5 +52,13 @@ pub fn remove_member(ctx: Context<RemoveMember>) -> Result<()> {
     if member_voucher.shares != 0 {
         return Err(HydraError::RemoveSharesMustBeZero.into());
     }
+    if member_voucher.scheduled_shares.is_some() {
+        return Err(HydraError::ShareChangePending.into());
+    }
+    emit!(MemberRemoved {
+        fanout: fanout.key(),
+        membership_key: member_voucher.membership_key,
+        membership_voucher: member_voucher.key(),
+    });
     Ok(())
 }
\ No newline at end of file
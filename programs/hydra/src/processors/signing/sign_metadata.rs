@@ -10,7 +10,7 @@ pub struct SignMetadata<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     #[account(
-    seeds = [b"fanout-config", fanout.name.as_bytes()],
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
     has_one = authority,
     bump
     )]
@@ -1,3 +1,5 @@
 pub mod set;
 pub mod set_for;
+pub mod set_weighted;
 pub mod unstake;
+pub mod unstake_weighted;
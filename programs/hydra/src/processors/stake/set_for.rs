@@ -1,6 +1,10 @@
 use crate::error::{HydraError, OrArithError};
-use crate::state::{Fanout, FanoutMembershipVoucher, FANOUT_MEMBERSHIP_VOUCHER_SIZE};
+use crate::events::MemberStaked;
+use crate::state::{
+    Fanout, FanoutMembershipVoucher, ACCOUNT_VERSION, FANOUT_MEMBERSHIP_VOUCHER_SIZE,
+};
 
+use crate::utils::logic::calculation::update_membership_supply;
 use crate::utils::validation::*;
 use crate::MembershipModel;
 use anchor_lang::prelude::*;
@@ -14,7 +18,7 @@ pub struct SetForTokenMemberStake<'info> {
     pub member: UncheckedAccount<'info>,
     #[account(
     mut,
-    seeds = [b"fanout-config", fanout.name.as_bytes()],
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
     bump = fanout.bump_seed,
     )]
     pub fanout: Account<'info, Fanout>,
@@ -72,10 +76,11 @@ pub fn set_for_token_member_stake(
     fanout.total_staked_shares = fanout
         .total_staked_shares
         .and_then(|ss| ss.checked_add(shares));
-    fanout.total_shares = membership_mint.supply;
+    update_membership_supply(fanout, membership_mint.supply);
     fanout.total_members = fanout.total_members.checked_add(1).or_arith_error()?;
     membership_voucher.shares = shares;
     membership_voucher.bump_seed = *ctx.bumps.get("membership_voucher").unwrap();
+    membership_voucher.version = ACCOUNT_VERSION;
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let accounts = anchor_spl::token::Transfer {
         from: ctx.accounts.membership_mint_token_account.to_account_info(),
@@ -84,5 +89,11 @@ pub fn set_for_token_member_stake(
     };
     let cpi_ctx = CpiContext::new(cpi_program, accounts);
     anchor_spl::token::transfer(cpi_ctx, shares)?;
+    emit!(MemberStaked {
+        fanout: fanout.key(),
+        member: member.key(),
+        shares,
+        total_staked_shares: fanout.total_staked_shares,
+    });
     Ok(())
 }
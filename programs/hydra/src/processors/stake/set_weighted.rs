@@ -0,0 +1,244 @@
+use crate::error::{HydraError, OrArithError};
+use crate::events::MemberStaked;
+use crate::state::{
+    Fanout, FanoutMembershipMint, FanoutMembershipStake, FanoutMembershipVoucher, ACCOUNT_VERSION,
+    FANOUT_MEMBERSHIP_STAKE_SIZE, FANOUT_MEMBERSHIP_VOUCHER_SIZE,
+};
+use crate::utils::logic::calculation::update_weighted_mint_supply;
+use crate::utils::validation::*;
+use crate::utils::{
+    create_or_allocate_account_raw, parse_fanout_mint_groups, read_mint_membership_voucher,
+    start_mint_membership_voucher,
+};
+use crate::MembershipModel;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::solana_program::sysvar::instructions::get_instruction_relative;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+#[instruction(shares: u64)]
+pub struct SetWeightedTokenMemberStake<'info> {
+    #[account(mut)]
+    pub member: Signer<'info>,
+    #[account(
+    mut,
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+    #[account(
+    mut,
+    seeds = [b"fanout-membership", fanout.key().as_ref(), member.key().as_ref()],
+    bump,
+    )]
+    /// CHECK: Created on the first stake
+    pub membership_voucher: UncheckedAccount<'info>,
+    #[account(
+    mut,
+    seeds = [b"fanout-membership-mint", fanout.key().as_ref(), membership_mint.key().as_ref()],
+    has_one = fanout,
+    bump = fanout_membership_mint.bump_seed,
+    )]
+    pub fanout_membership_mint: Account<'info, FanoutMembershipMint>,
+    pub membership_mint: Account<'info, Mint>,
+    #[account(
+    mut,
+    seeds = [b"fanout-membership-stake", fanout_membership_mint.key().as_ref(), member.key().as_ref()],
+    bump,
+    )]
+    /// CHECK: Created on the first stake of this membership mint
+    pub membership_stake: UncheckedAccount<'info>,
+    #[account(
+    mut,
+    constraint = membership_mint_token_account.mint == membership_mint.key(),
+    constraint = membership_mint_token_account.delegate.is_none(),
+    constraint = membership_mint_token_account.close_authority.is_none(),
+    constraint = membership_mint_token_account.amount >= shares,
+    constraint = membership_mint_token_account.owner == member.key()
+    )]
+    pub membership_mint_token_account: Account<'info, TokenAccount>,
+    #[account(
+    mut,
+    constraint = member_stake_account.owner == membership_voucher.key(),
+    constraint = member_stake_account.mint == membership_mint.key(),
+    )]
+    pub member_stake_account: Account<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = sysvar::instructions::id())]
+    /// CHECK: Instructions SYSVAR
+    pub instructions: UncheckedAccount<'info>,
+}
+
+// Remaining accounts are (fanout mint, holding account, mint membership voucher) for every
+// registered mint. A new member's mint vouchers are created at each mint's current inflow, an
+// existing member must be settled on every mint before the stake is added.
+pub fn set_weighted_token_member_stake<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetWeightedTokenMemberStake<'info>>,
+    shares: u64,
+) -> Result<()> {
+    let fanout = &mut ctx.accounts.fanout;
+    let member = &ctx.accounts.member;
+    let fanout_membership_mint = &mut ctx.accounts.fanout_membership_mint;
+    let membership_voucher_info = ctx.accounts.membership_voucher.to_account_info();
+    assert_owned_by(&fanout.to_account_info(), &crate::ID)?;
+    assert_owned_by(&member.to_account_info(), &System::id())?;
+    assert_membership_model(fanout, MembershipModel::Token)?;
+    assert_ata(
+        &ctx.accounts.member_stake_account.to_account_info(),
+        &membership_voucher_info.key(),
+        &ctx.accounts.membership_mint.key(),
+        Some(HydraError::InvalidStakeAta.into()),
+    )?;
+    let bump_seed = *ctx.bumps.get("membership_voucher").unwrap();
+    let creates_voucher = membership_voucher_info.data_is_empty();
+    let mut membership_voucher = if creates_voucher {
+        create_or_allocate_account_raw(
+            crate::ID,
+            &membership_voucher_info,
+            &ctx.accounts.rent.to_account_info(),
+            &ctx.accounts.system_program,
+            &member.to_account_info(),
+            FANOUT_MEMBERSHIP_VOUCHER_SIZE,
+            &[],
+            &[
+                b"fanout-membership",
+                fanout.key().as_ref(),
+                member.key().as_ref(),
+                &[bump_seed],
+            ],
+        )?;
+        fanout.total_members = fanout.total_members.checked_add(1).or_arith_error()?;
+        // New members only take part in inflow from here on
+        FanoutMembershipVoucher {
+            fanout: fanout.key(),
+            total_inflow: 0,
+            last_inflow: fanout.total_inflow,
+            bump_seed,
+            membership_key: member.key(),
+            shares: 0,
+            payout_destination: None,
+            expires_at: None,
+            scheduled_shares: None,
+            share_epoch: 0,
+            previous_shares: 0,
+            version: ACCOUNT_VERSION,
+            label: None,
+            last_distribution_window: None,
+            rent_payer: Some(member.key()),
+        }
+    } else {
+        // Existing shares are settled, native and per mint, before the weighted stake is added
+        let prev_ix = get_instruction_relative(-1, &ctx.accounts.instructions).unwrap();
+        assert_distributed(prev_ix, member.key, fanout.membership_model)?;
+        assert_owned_by(&membership_voucher_info, &crate::ID)?;
+        let mut membership_voucher_data: &[u8] = &membership_voucher_info.try_borrow_data()?;
+        let membership_voucher =
+            FanoutMembershipVoucher::try_deserialize(&mut membership_voucher_data)?;
+        assert_settled(fanout, &membership_voucher)?;
+        membership_voucher
+    };
+    let fanout_key = fanout.key();
+    let mint_groups = parse_fanout_mint_groups(fanout, &fanout_key, ctx.remaining_accounts, 3)?;
+    for (mut fanout_for_mint, group) in mint_groups {
+        if creates_voucher {
+            start_mint_membership_voucher(
+                fanout,
+                &mut fanout_for_mint,
+                &group[0],
+                &group[1],
+                &group[2],
+                member.key,
+                &ctx.accounts.rent,
+                &ctx.accounts.system_program,
+                &member.to_account_info(),
+            )?;
+        } else {
+            let mint_voucher = read_mint_membership_voucher(
+                &group[2],
+                group[0].key,
+                member.key,
+                &fanout_for_mint.mint,
+            )?;
+            assert_mint_settled(&fanout_for_mint, &group[1], mint_voucher.as_ref())?;
+        }
+    }
+    // Stakes are tracked per mint, tokens sent straight to the stake account earn nothing
+    let membership_stake_info = ctx.accounts.membership_stake.to_account_info();
+    let stake_bump_seed = *ctx.bumps.get("membership_stake").unwrap();
+    let mut membership_stake = if membership_stake_info.data_is_empty() {
+        create_or_allocate_account_raw(
+            crate::ID,
+            &membership_stake_info,
+            &ctx.accounts.rent.to_account_info(),
+            &ctx.accounts.system_program,
+            &member.to_account_info(),
+            FANOUT_MEMBERSHIP_STAKE_SIZE,
+            &[],
+            &[
+                b"fanout-membership-stake",
+                fanout_membership_mint.key().as_ref(),
+                member.key().as_ref(),
+                &[stake_bump_seed],
+            ],
+        )?;
+        FanoutMembershipStake {
+            fanout: fanout.key(),
+            membership_key: member.key(),
+            membership_mint: fanout_membership_mint.mint,
+            amount: 0,
+            bump_seed: stake_bump_seed,
+            version: ACCOUNT_VERSION,
+        }
+    } else {
+        assert_owned_by(&membership_stake_info, &crate::ID)?;
+        let mut membership_stake_data: &[u8] = &membership_stake_info.try_borrow_data()?;
+        FanoutMembershipStake::try_deserialize(&mut membership_stake_data)?
+    };
+    membership_stake.amount = membership_stake
+        .amount
+        .checked_add(shares)
+        .or_arith_error()?;
+    let weighted_shares = shares
+        .checked_mul(fanout_membership_mint.weight)
+        .or_arith_error()?;
+    membership_voucher.shares = membership_voucher
+        .shares
+        .checked_add(weighted_shares)
+        .or_arith_error()?;
+    fanout.total_staked_shares = fanout
+        .total_staked_shares
+        .and_then(|ss| ss.checked_add(weighted_shares));
+    fanout_membership_mint.total_staked = fanout_membership_mint
+        .total_staked
+        .checked_add(shares)
+        .or_arith_error()?;
+    update_weighted_mint_supply(
+        fanout,
+        fanout_membership_mint,
+        ctx.accounts.membership_mint.supply,
+    )?;
+    let mut membership_voucher_data: &mut [u8] =
+        &mut membership_voucher_info.try_borrow_mut_data()?;
+    membership_voucher.try_serialize(&mut membership_voucher_data)?;
+    let mut membership_stake_data: &mut [u8] = &mut membership_stake_info.try_borrow_mut_data()?;
+    membership_stake.try_serialize(&mut membership_stake_data)?;
+
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let accounts = anchor_spl::token::Transfer {
+        from: ctx.accounts.membership_mint_token_account.to_account_info(),
+        to: ctx.accounts.member_stake_account.to_account_info(),
+        authority: member.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(cpi_program, accounts);
+    anchor_spl::token::transfer(cpi_ctx, shares)?;
+    emit!(MemberStaked {
+        fanout: fanout.key(),
+        member: member.key(),
+        shares: weighted_shares,
+        total_staked_shares: fanout.total_staked_shares,
+    });
+    Ok(())
+}
@@ -1,6 +1,8 @@
-use crate::error::OrArithError;
+use crate::error::{HydraError, OrArithError};
+use crate::events::MemberUnstaked;
 use crate::state::{Fanout, FanoutMembershipVoucher};
 
+use crate::utils::logic::calculation::update_membership_supply;
 use crate::utils::validation::*;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::sysvar;
@@ -13,7 +15,7 @@ pub struct UnStakeTokenMember<'info> {
     pub member: Signer<'info>,
     #[account(
     mut,
-    seeds = [b"fanout-config", fanout.name.as_bytes()],
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
     bump = fanout.bump_seed,
     )]
     pub fanout: Account<'info, Fanout>,
@@ -57,14 +59,18 @@ pub fn unstake(ctx: Context<UnStakeTokenMember>) -> Result<()> {
     let membership_mint = &mut ctx.accounts.membership_mint;
     let prev_ix = get_instruction_relative(-1, ixs).unwrap();
     assert_distributed(prev_ix, member.key, fanout.membership_model)?;
+    assert_settled(fanout, &ctx.accounts.membership_voucher)?;
     assert_owned_by(&fanout.to_account_info(), &crate::ID)?;
     assert_owned_by(&member.to_account_info(), &System::id())?;
     let amount = ctx.accounts.member_stake_account.amount;
+    if ctx.accounts.membership_voucher.shares > amount {
+        return Err(HydraError::WeightedStakeRemaining.into());
+    }
     fanout.total_staked_shares = fanout
         .total_staked_shares
         .to_owned()
         .map(|tss| tss.checked_sub(amount).or_arith_error().unwrap());
-    fanout.total_shares = membership_mint.supply;
+    update_membership_supply(fanout, membership_mint.supply);
     fanout.total_members = fanout.total_members.checked_sub(1).or_arith_error()?;
     let stake_account_info = ctx.accounts.member_stake_account.to_account_info();
     let cpi_program = ctx.accounts.token_program.to_account_info();
@@ -84,5 +90,11 @@ pub fn unstake(ctx: Context<UnStakeTokenMember>) -> Result<()> {
         ]]),
         amount,
     )?;
+    emit!(MemberUnstaked {
+        fanout: fanout.key(),
+        member: member.key(),
+        shares: amount,
+        total_staked_shares: fanout.total_staked_shares,
+    });
     Ok(())
 }
@@ -0,0 +1,143 @@
+use crate::error::{HydraError, OrArithError};
+use crate::events::MemberUnstaked;
+use crate::state::{Fanout, FanoutMembershipMint, FanoutMembershipStake, FanoutMembershipVoucher};
+use crate::utils::close_account_raw;
+use crate::utils::logic::calculation::update_weighted_mint_supply;
+use crate::utils::validation::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::solana_program::sysvar::instructions::get_instruction_relative;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct UnStakeWeightedTokenMember<'info> {
+    #[account(mut)]
+    pub member: Signer<'info>,
+    #[account(
+    mut,
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+    #[account(
+    mut,
+    seeds = [b"fanout-membership", fanout.key().as_ref(), member.key().as_ref()],
+    bump,
+    )]
+    /// CHECK: Closed once no membership mint remains staked
+    pub membership_voucher: UncheckedAccount<'info>,
+    #[account(
+    mut,
+    seeds = [b"fanout-membership-mint", fanout.key().as_ref(), membership_mint.key().as_ref()],
+    has_one = fanout,
+    bump = fanout_membership_mint.bump_seed,
+    )]
+    pub fanout_membership_mint: Account<'info, FanoutMembershipMint>,
+    pub membership_mint: Account<'info, Mint>,
+    #[account(
+    mut,
+    seeds = [b"fanout-membership-stake", fanout_membership_mint.key().as_ref(), member.key().as_ref()],
+    bump,
+    )]
+    /// CHECK: Closed once the membership mint is unstaked
+    pub membership_stake: UncheckedAccount<'info>,
+    #[account(
+    mut,
+    constraint = membership_mint_token_account.mint == membership_mint.key(),
+    constraint = membership_mint_token_account.delegate.is_none(),
+    constraint = membership_mint_token_account.close_authority.is_none(),
+    )]
+    pub membership_mint_token_account: Account<'info, TokenAccount>,
+    #[account(
+    mut,
+    constraint = member_stake_account.owner == membership_voucher.key(),
+    constraint = member_stake_account.mint == membership_mint.key(),
+    )]
+    pub member_stake_account: Account<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    #[account(address = sysvar::instructions::id())]
+    /// CHECK: Instructions SYSVAR
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn unstake_weighted(ctx: Context<UnStakeWeightedTokenMember>) -> Result<()> {
+    let fanout = &mut ctx.accounts.fanout;
+    let member = &ctx.accounts.member;
+    let membership_voucher_info = ctx.accounts.membership_voucher.to_account_info();
+    let fanout_membership_mint = &mut ctx.accounts.fanout_membership_mint;
+    let prev_ix = get_instruction_relative(-1, &ctx.accounts.instructions).unwrap();
+    assert_distributed(prev_ix, member.key, fanout.membership_model)?;
+    assert_owned_by(&fanout.to_account_info(), &crate::ID)?;
+    assert_owned_by(&member.to_account_info(), &System::id())?;
+    assert_owned_by(&membership_voucher_info, &crate::ID)?;
+    let mut membership_voucher = {
+        let mut membership_voucher_data: &[u8] = &membership_voucher_info.try_borrow_data()?;
+        FanoutMembershipVoucher::try_deserialize(&mut membership_voucher_data)?
+    };
+    if membership_voucher.membership_key != member.key() {
+        return Err(HydraError::InvalidMembershipVoucher.into());
+    }
+    assert_settled(fanout, &membership_voucher)?;
+    let membership_stake_info = ctx.accounts.membership_stake.to_account_info();
+    assert_owned_by(&membership_stake_info, &crate::ID)?;
+    // Shares come off by the recorded stake, the whole stake account balance goes back
+    let amount = {
+        let mut membership_stake_data: &[u8] = &membership_stake_info.try_borrow_data()?;
+        FanoutMembershipStake::try_deserialize(&mut membership_stake_data)?.amount
+    };
+    let weighted_shares = amount
+        .checked_mul(fanout_membership_mint.weight)
+        .or_arith_error()?;
+    membership_voucher.shares = membership_voucher
+        .shares
+        .checked_sub(weighted_shares)
+        .or_arith_error()?;
+    fanout.total_staked_shares = fanout
+        .total_staked_shares
+        .map(|tss| tss.checked_sub(weighted_shares))
+        .map(|tss| tss.or_arith_error())
+        .transpose()?;
+    fanout_membership_mint.total_staked = fanout_membership_mint
+        .total_staked
+        .checked_sub(amount)
+        .or_arith_error()?;
+    update_weighted_mint_supply(
+        fanout,
+        fanout_membership_mint,
+        ctx.accounts.membership_mint.supply,
+    )?;
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let accounts = anchor_spl::token::Transfer {
+        from: ctx.accounts.member_stake_account.to_account_info(),
+        to: ctx.accounts.membership_mint_token_account.to_account_info(),
+        authority: membership_voucher_info.clone(),
+    };
+    let cpi_ctx = CpiContext::new(cpi_program, accounts);
+    anchor_spl::token::transfer(
+        cpi_ctx.with_signer(&[&[
+            "fanout-membership".as_bytes(),
+            fanout.key().as_ref(),
+            member.key().as_ref(),
+            &[*ctx.bumps.get("membership_voucher").unwrap()],
+        ]]),
+        ctx.accounts.member_stake_account.amount,
+    )?;
+    close_account_raw(&membership_stake_info, &member.to_account_info())?;
+    // The voucher is kept while other membership mints remain staked
+    if membership_voucher.shares == 0 {
+        fanout.total_members = fanout.total_members.checked_sub(1).or_arith_error()?;
+        close_account_raw(&membership_voucher_info, &member.to_account_info())?;
+    } else {
+        let mut membership_voucher_data: &mut [u8] =
+            &mut membership_voucher_info.try_borrow_mut_data()?;
+        membership_voucher.try_serialize(&mut membership_voucher_data)?;
+    }
+    emit!(MemberUnstaked {
+        fanout: fanout.key(),
+        member: member.key(),
+        shares: weighted_shares,
+        total_staked_shares: fanout.total_staked_shares,
+    });
+    Ok(())
+}
@@ -0,0 +1,192 @@
+use crate::error::{HydraError, OrArithError};
+use crate::events::SharesTransferred;
+use crate::state::{
+    Fanout, FanoutMembershipVoucher, ACCOUNT_VERSION, FANOUT_MEMBERSHIP_VOUCHER_SIZE,
+};
+use crate::utils::validation::{
+    assert_distributed, assert_membership_model, assert_mint_settled, assert_owned_by,
+    assert_settled,
+};
+use crate::utils::{
+    create_or_allocate_account_raw, parse_fanout_mint_groups, read_mint_membership_voucher,
+    start_mint_membership_voucher,
+};
+use crate::MembershipModel;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::solana_program::sysvar::instructions::get_instruction_relative;
+
+#[derive(Accounts)]
+#[instruction(shares: u64)]
+pub struct MemberTransferShares<'info> {
+    #[account(mut)]
+    pub from_member: Signer<'info>,
+    /// CHECK: Native Account
+    pub to_member: UncheckedAccount<'info>,
+    #[account(
+    mut,
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+    #[account(
+    mut,
+    seeds = [b"fanout-membership", fanout.key().as_ref(), from_member.key().as_ref()],
+    bump = from_membership_account.bump_seed,
+    has_one = fanout,
+    )]
+    pub from_membership_account: Account<'info, FanoutMembershipVoucher>,
+    #[account(
+    mut,
+    seeds = [b"fanout-membership", fanout.key().as_ref(), to_member.key().as_ref()],
+    bump,
+    )]
+    /// CHECK: Created if the recipient is not yet a member
+    pub to_membership_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = sysvar::instructions::id())]
+    /// CHECK: Instructions SYSVAR
+    pub instructions: UncheckedAccount<'info>,
+}
+
+// Both members settle by distributing in the preceding instructions, the sender last. Remaining
+// accounts are (fanout mint, holding account, sender mint voucher, recipient mint voucher) for
+// every registered mint, whose inflow must also be distributed to both members. A new recipient's
+// mint vouchers are created at each mint's current inflow.
+pub fn member_transfer_shares<'info>(
+    ctx: Context<'_, '_, '_, 'info, MemberTransferShares<'info>>,
+    shares: u64,
+) -> Result<()> {
+    let fanout = &mut ctx.accounts.fanout;
+    let from_member = &ctx.accounts.from_member;
+    let to_member = &ctx.accounts.to_member;
+    let from_membership_account = &mut ctx.accounts.from_membership_account;
+    let to_membership_info = ctx.accounts.to_membership_account.to_account_info();
+    let ixs = &ctx.accounts.instructions;
+    if !fanout.member_transfers_enabled {
+        return Err(HydraError::MemberTransfersDisabled.into());
+    }
+    assert_membership_model(fanout, MembershipModel::Wallet)?;
+    if to_member.key() == from_member.key() {
+        return Err(HydraError::CannotTransferToSelf.into());
+    }
+    if from_membership_account.shares < shares {
+        return Err(HydraError::InsufficientShares.into());
+    }
+    if from_membership_account.scheduled_shares.is_some() {
+        return Err(HydraError::ShareChangePending.into());
+    }
+    if let Some(expires_at) = from_membership_account.expires_at {
+        if Clock::get()?.unix_timestamp >= expires_at {
+            return Err(HydraError::MembershipExpired.into());
+        }
+    }
+    assert_distributed(
+        get_instruction_relative(-1, ixs).unwrap(),
+        from_member.key,
+        fanout.membership_model,
+    )?;
+    assert_settled(fanout, from_membership_account)?;
+    let bump_seed = *ctx.bumps.get("to_membership_account").unwrap();
+    let creates_recipient = to_membership_info.data_is_empty();
+    let mut to_membership_account = if creates_recipient {
+        create_or_allocate_account_raw(
+            crate::ID,
+            &to_membership_info,
+            &ctx.accounts.rent.to_account_info(),
+            &ctx.accounts.system_program,
+            &from_member.to_account_info(),
+            FANOUT_MEMBERSHIP_VOUCHER_SIZE,
+            &[],
+            &[
+                b"fanout-membership",
+                fanout.key().as_ref(),
+                to_member.key().as_ref(),
+                &[bump_seed],
+            ],
+        )?;
+        fanout.total_members = fanout.total_members.checked_add(1).or_arith_error()?;
+        FanoutMembershipVoucher {
+            fanout: fanout.key(),
+            total_inflow: 0,
+            last_inflow: fanout.total_inflow,
+            bump_seed,
+            membership_key: to_member.key(),
+            shares: 0,
+            payout_destination: None,
+            expires_at: None,
+            scheduled_shares: None,
+            share_epoch: 0,
+            previous_shares: 0,
+            version: ACCOUNT_VERSION,
+            label: None,
+            last_distribution_window: None,
+            rent_payer: Some(from_member.key()),
+        }
+    } else {
+        assert_distributed(
+            get_instruction_relative(-2, ixs).unwrap(),
+            to_member.key,
+            fanout.membership_model,
+        )?;
+        assert_owned_by(&to_membership_info, &crate::ID)?;
+        let mut to_membership_data: &[u8] = &to_membership_info.try_borrow_data()?;
+        let to_membership_account =
+            FanoutMembershipVoucher::try_deserialize(&mut to_membership_data)?;
+        assert_settled(fanout, &to_membership_account)?;
+        to_membership_account
+    };
+    if to_membership_account.fanout != fanout.key() {
+        return Err(HydraError::InvalidMembershipVoucher.into());
+    }
+    if to_membership_account.scheduled_shares.is_some() {
+        return Err(HydraError::ShareChangePending.into());
+    }
+    let fanout_key = fanout.key();
+    let mint_groups = parse_fanout_mint_groups(fanout, &fanout_key, ctx.remaining_accounts, 4)?;
+    for (mut fanout_for_mint, group) in mint_groups {
+        let from_mint_voucher = read_mint_membership_voucher(
+            &group[2],
+            group[0].key,
+            &from_membership_account.membership_key,
+            &fanout_for_mint.mint,
+        )?;
+        assert_mint_settled(&fanout_for_mint, &group[1], from_mint_voucher.as_ref())?;
+        if creates_recipient {
+            start_mint_membership_voucher(
+                fanout,
+                &mut fanout_for_mint,
+                &group[0],
+                &group[1],
+                &group[3],
+                to_member.key,
+                &ctx.accounts.rent,
+                &ctx.accounts.system_program,
+                &from_member.to_account_info(),
+            )?;
+        } else {
+            let to_mint_voucher = read_mint_membership_voucher(
+                &group[3],
+                group[0].key,
+                to_member.key,
+                &fanout_for_mint.mint,
+            )?;
+            assert_mint_settled(&fanout_for_mint, &group[1], to_mint_voucher.as_ref())?;
+        }
+    }
+    from_membership_account.shares -= shares;
+    to_membership_account.shares = to_membership_account
+        .shares
+        .checked_add(shares)
+        .or_arith_error()?;
+    let mut to_membership_data: &mut [u8] = &mut to_membership_info.try_borrow_mut_data()?;
+    to_membership_account.try_serialize(&mut to_membership_data)?;
+    emit!(SharesTransferred {
+        fanout: fanout.key(),
+        from_membership_key: from_membership_account.membership_key,
+        to_membership_key: to_membership_account.membership_key,
+        shares,
+    });
+    Ok(())
+}
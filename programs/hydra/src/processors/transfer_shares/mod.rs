@@ -1 +1,2 @@
+pub mod member_transfer_shares;
 pub mod transfer_shares;
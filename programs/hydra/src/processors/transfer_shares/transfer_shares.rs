@@ -1,4 +1,5 @@
 use crate::error::HydraError;
+use crate::events::SharesTransferred;
 use crate::state::{Fanout, FanoutMembershipVoucher};
 
 use crate::MembershipModel;
@@ -16,7 +17,7 @@ pub struct TransferShares<'info> {
     pub to_member: UncheckedAccount<'info>,
     #[account(
     mut,
-    seeds = [b"fanout-config", fanout.name.as_bytes()],
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
     has_one = authority,
     bump = fanout.bump_seed,
     )]
@@ -55,7 +56,19 @@ pub fn transfer_shares(ctx: Context<TransferShares>, shares: u64) -> Result<()>
     {
         return Err(HydraError::TransferNotSupported.into());
     }
+
+    if from_membership_account.scheduled_shares.is_some()
+        || to_membership_account.scheduled_shares.is_some()
+    {
+        return Err(HydraError::ShareChangePending.into());
+    }
     from_membership_account.shares -= shares;
     to_membership_account.shares += shares;
+    emit!(SharesTransferred {
+        fanout: fanout.key(),
+        from_membership_key: from_membership_account.membership_key,
+        to_membership_key: to_membership_account.membership_key,
+        shares,
+    });
     Ok(())
 }
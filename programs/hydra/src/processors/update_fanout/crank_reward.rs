@@ -0,0 +1,36 @@
+use crate::error::HydraError;
+use crate::events::FanoutUpdated;
+use crate::state::{CrankReward, Fanout, MAX_CRANK_REWARD_BASIS_POINTS};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(crank_reward: Option<CrankReward>)]
+pub struct SetCrankReward<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+    mut,
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    has_one = authority,
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+}
+
+// Crank rewards are paid to the signer of distributions, in lamports for native distributions and
+// in the mint for basis point rewards on mint distributions.
+pub fn set_crank_reward(
+    ctx: Context<SetCrankReward>,
+    crank_reward: Option<CrankReward>,
+) -> Result<()> {
+    let fanout = &mut ctx.accounts.fanout;
+    if let Some(CrankReward::BasisPoints { basis_points, .. }) = crank_reward {
+        if basis_points > MAX_CRANK_REWARD_BASIS_POINTS {
+            return Err(HydraError::InvalidCrankReward.into());
+        }
+    }
+    fanout.crank_reward = crank_reward;
+    emit!(FanoutUpdated {
+        fanout: fanout.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use crate::error::HydraError;
+use crate::events::FanoutUpdated;
+use crate::state::{DistributionWindow, DistributionWindowScope, Fanout};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    distribution_window: Option<DistributionWindow>,
+    distribution_window_scope: DistributionWindowScope,
+)]
+pub struct SetDistributionWindow<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+    mut,
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    has_one = authority,
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+}
+
+pub fn set_distribution_window(
+    ctx: Context<SetDistributionWindow>,
+    distribution_window: Option<DistributionWindow>,
+    distribution_window_scope: DistributionWindowScope,
+) -> Result<()> {
+    let fanout = &mut ctx.accounts.fanout;
+    match distribution_window {
+        Some(DistributionWindow::Epochs { epochs: 0 })
+        | Some(DistributionWindow::Seconds { seconds: 0 }) => {
+            return Err(HydraError::InvalidDistributionWindow.into());
+        }
+        _ => {}
+    }
+    fanout.distribution_window = distribution_window;
+    fanout.distribution_window_scope = distribution_window_scope;
+    fanout.last_distribution_window = None;
+    emit!(FanoutUpdated {
+        fanout: fanout.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use crate::error::HydraError;
+use crate::events::FanoutUpdated;
+use crate::state::Fanout;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(fee_basis_points: u16)]
+pub struct SetFee<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+    mut,
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    has_one = authority,
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+    /// CHECK: Any account can receive fees
+    pub fee_recipient: UncheckedAccount<'info>,
+}
+
+pub fn set_fee(ctx: Context<SetFee>, fee_basis_points: u16) -> Result<()> {
+    let fanout = &mut ctx.accounts.fanout;
+    if fee_basis_points > 10000 {
+        return Err(HydraError::InvalidFee.into());
+    }
+    fanout.fee_basis_points = fee_basis_points;
+    fanout.fee_recipient = if fee_basis_points == 0 {
+        None
+    } else {
+        Some(ctx.accounts.fee_recipient.key())
+    };
+    emit!(FanoutUpdated {
+        fanout: fanout.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use crate::events::FanoutUpdated;
+use crate::state::Fanout;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(member_transfers_enabled: bool)]
+pub struct SetMemberTransfers<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+    mut,
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    has_one = authority,
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+}
+
+pub fn set_member_transfers(
+    ctx: Context<SetMemberTransfers>,
+    member_transfers_enabled: bool,
+) -> Result<()> {
+    let fanout = &mut ctx.accounts.fanout;
+    fanout.member_transfers_enabled = member_transfers_enabled;
+    emit!(FanoutUpdated {
+        fanout: fanout.key(),
+    });
+    Ok(())
+}
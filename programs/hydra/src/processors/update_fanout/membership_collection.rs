@@ -0,0 +1,37 @@
+use crate::events::FanoutUpdated;
+use crate::state::{Fanout, MembershipModel};
+use crate::utils::validation::assert_membership_model;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(shares_per_nft: u64)]
+pub struct SetMembershipCollection<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+    mut,
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    has_one = authority,
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+    /// CHECK: The collection mint, matched against verified NFT metadata on join
+    pub collection: UncheckedAccount<'info>,
+}
+
+pub fn set_membership_collection(
+    ctx: Context<SetMembershipCollection>,
+    shares_per_nft: u64,
+) -> Result<()> {
+    let fanout = &mut ctx.accounts.fanout;
+    assert_membership_model(fanout, MembershipModel::NFT)?;
+    fanout.shares_per_nft = shares_per_nft;
+    fanout.membership_collection = if shares_per_nft == 0 {
+        None
+    } else {
+        Some(ctx.accounts.collection.key())
+    };
+    emit!(FanoutUpdated {
+        fanout: fanout.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use crate::error::HydraError;
+use crate::events::FanoutUpdated;
+use crate::state::{
+    Fanout, FanoutMetadata, ACCOUNT_VERSION, FANOUT_METADATA_SIZE, MAX_METADATA_DESCRIPTION_LENGTH,
+    MAX_METADATA_URI_LENGTH,
+};
+use crate::utils::create_or_allocate_account_raw;
+use crate::utils::validation::{assert_derivation, assert_owned_by};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(uri: String, description: String)]
+pub struct SetFanoutMetadata<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    has_one = authority,
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+    #[account(
+    mut,
+    seeds = [b"fanout-metadata", fanout.key().as_ref()],
+    bump,
+    )]
+    /// CHECK: Created on first use
+    pub fanout_metadata: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+// Metadata lives in its own account so it can be set with init or any time after.
+pub fn set_fanout_metadata(
+    ctx: Context<SetFanoutMetadata>,
+    uri: String,
+    description: String,
+) -> Result<()> {
+    let fanout = &ctx.accounts.fanout;
+    write_fanout_metadata(
+        &fanout.key(),
+        &ctx.accounts.fanout_metadata.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.rent,
+        &ctx.accounts.system_program,
+        uri,
+        description,
+    )?;
+    emit!(FanoutUpdated {
+        fanout: fanout.key(),
+    });
+    Ok(())
+}
+
+pub fn write_fanout_metadata<'info>(
+    fanout: &Pubkey,
+    metadata_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    rent: &Sysvar<'info, Rent>,
+    system_program: &Program<'info, System>,
+    uri: String,
+    description: String,
+) -> Result<()> {
+    if uri.len() > MAX_METADATA_URI_LENGTH || description.len() > MAX_METADATA_DESCRIPTION_LENGTH {
+        return Err(HydraError::MetadataTooLong.into());
+    }
+    let bump_seed = assert_derivation(
+        &crate::ID,
+        metadata_info,
+        &[b"fanout-metadata", fanout.as_ref()],
+        Some(HydraError::InvalidFanoutMetadata.into()),
+    )?;
+    let mut fanout_metadata = if metadata_info.data_is_empty() {
+        create_or_allocate_account_raw(
+            crate::ID,
+            metadata_info,
+            &rent.to_account_info(),
+            system_program,
+            payer,
+            FANOUT_METADATA_SIZE,
+            &[],
+            &[b"fanout-metadata", fanout.as_ref(), &[bump_seed]],
+        )?;
+        FanoutMetadata {
+            fanout: *fanout,
+            bump_seed,
+            version: ACCOUNT_VERSION,
+            ..Default::default()
+        }
+    } else {
+        assert_owned_by(metadata_info, &crate::ID)?;
+        let mut metadata_data: &[u8] = &metadata_info.try_borrow_data()?;
+        FanoutMetadata::try_deserialize(&mut metadata_data)?
+    };
+    fanout_metadata.uri = uri;
+    fanout_metadata.description = description;
+    let mut metadata_data: &mut [u8] = &mut metadata_info.try_borrow_mut_data()?;
+    fanout_metadata.try_serialize(&mut metadata_data)
+}
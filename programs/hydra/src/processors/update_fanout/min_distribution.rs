@@ -0,0 +1,25 @@
+use crate::events::FanoutUpdated;
+use crate::state::Fanout;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(min_distribution: u64)]
+pub struct SetMinDistribution<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+    mut,
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    has_one = authority,
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+}
+
+pub fn set_min_distribution(ctx: Context<SetMinDistribution>, min_distribution: u64) -> Result<()> {
+    let fanout = &mut ctx.accounts.fanout;
+    fanout.min_distribution = min_distribution;
+    emit!(FanoutUpdated {
+        fanout: fanout.key(),
+    });
+    Ok(())
+}
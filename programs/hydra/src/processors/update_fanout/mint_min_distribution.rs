@@ -0,0 +1,34 @@
+use crate::events::FanoutUpdated;
+use crate::state::{Fanout, FanoutMint};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(min_distribution: u64)]
+pub struct SetMintMinDistribution<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    has_one = authority,
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+    #[account(
+    mut,
+    seeds = [b"fanout-config", fanout.key().as_ref(), fanout_for_mint.mint.as_ref()],
+    has_one = fanout,
+    bump = fanout_for_mint.bump_seed,
+    )]
+    pub fanout_for_mint: Account<'info, FanoutMint>,
+}
+
+// Set in the mint's own units, the fanout's threshold only applies to native distributions.
+pub fn set_mint_min_distribution(
+    ctx: Context<SetMintMinDistribution>,
+    min_distribution: u64,
+) -> Result<()> {
+    ctx.accounts.fanout_for_mint.min_distribution = min_distribution;
+    emit!(FanoutUpdated {
+        fanout: ctx.accounts.fanout.key(),
+    });
+    Ok(())
+}
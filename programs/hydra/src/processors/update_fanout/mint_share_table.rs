@@ -0,0 +1,54 @@
+use crate::error::HydraError;
+use crate::events::FanoutUpdated;
+use crate::state::{Fanout, FanoutMint, MembershipModel};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(total_shares: Option<u64>)]
+pub struct SetMintShareTable<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    has_one = authority,
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+    #[account(
+    mut,
+    seeds = [b"fanout-config", fanout.key().as_ref(), fanout_for_mint.mint.as_ref()],
+    has_one = fanout,
+    bump = fanout_for_mint.bump_seed,
+    )]
+    pub fanout_for_mint: Account<'info, FanoutMint>,
+}
+
+// The table is fixed before the mint's first distribution so no recorded inflow is ever
+// split two ways.
+pub fn set_mint_share_table(
+    ctx: Context<SetMintShareTable>,
+    total_shares: Option<u64>,
+) -> Result<()> {
+    let fanout = &ctx.accounts.fanout;
+    let fanout_for_mint = &mut ctx.accounts.fanout_for_mint;
+    if fanout.membership_model != MembershipModel::Wallet
+        && fanout.membership_model != MembershipModel::NFT
+    {
+        return Err(HydraError::InvalidMembershipModel.into());
+    }
+    if total_shares == Some(0) {
+        return Err(HydraError::InvalidShareTable.into());
+    }
+    let allocated = match fanout_for_mint.total_shares {
+        Some(current) => current != fanout_for_mint.total_available_shares,
+        None => false,
+    };
+    if fanout_for_mint.total_inflow > 0 || allocated {
+        return Err(HydraError::ShareTableLocked.into());
+    }
+    fanout_for_mint.total_shares = total_shares;
+    fanout_for_mint.total_available_shares = total_shares.unwrap_or(0);
+    emit!(FanoutUpdated {
+        fanout: fanout.key(),
+    });
+    Ok(())
+}
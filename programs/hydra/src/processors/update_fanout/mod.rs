@@ -0,0 +1,14 @@
+pub mod crank_reward;
+pub mod distribution_window;
+pub mod fee;
+pub mod member_transfers;
+pub mod membership_collection;
+pub mod metadata;
+pub mod min_distribution;
+pub mod mint_min_distribution;
+pub mod mint_share_table;
+pub mod reserve;
+pub mod resignation_policy;
+pub mod shares_root;
+pub mod unstaked_policy;
+pub mod unwrap_native_mint;
@@ -0,0 +1,43 @@
+use crate::error::HydraError;
+use crate::events::FanoutUpdated;
+use crate::state::{Fanout, MembershipModel};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(enabled: bool)]
+pub struct SetReserve<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+    mut,
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    has_one = authority,
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+    /// CHECK: Any account can hold the reserve
+    pub reserve: UncheckedAccount<'info>,
+}
+
+// Unallocated shares accrue their part of each inflow to the reserve, so distribution can start
+// before every share is assigned. Inflow owed to the old reserve, native or in any mint, must be
+// claimed first.
+pub fn set_reserve(ctx: Context<SetReserve>, enabled: bool) -> Result<()> {
+    let fanout = &mut ctx.accounts.fanout;
+    if fanout.membership_model != MembershipModel::Wallet
+        && fanout.membership_model != MembershipModel::NFT
+    {
+        return Err(HydraError::ReserveNotSupported.into());
+    }
+    if fanout.reserve_unclaimed != 0 || fanout.mints_with_reserve != 0 {
+        return Err(HydraError::ReserveHasUnclaimedInflow.into());
+    }
+    fanout.reserve = if enabled {
+        Some(ctx.accounts.reserve.key())
+    } else {
+        None
+    };
+    emit!(FanoutUpdated {
+        fanout: fanout.key(),
+    });
+    Ok(())
+}
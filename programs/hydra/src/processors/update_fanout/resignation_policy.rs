@@ -0,0 +1,30 @@
+use crate::events::FanoutUpdated;
+use crate::state::{Fanout, MembershipModel, ResignationPolicy};
+use crate::utils::validation::assert_membership_model;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(resignation_policy: ResignationPolicy)]
+pub struct SetResignationPolicy<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+    mut,
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    has_one = authority,
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+}
+
+pub fn set_resignation_policy(
+    ctx: Context<SetResignationPolicy>,
+    resignation_policy: ResignationPolicy,
+) -> Result<()> {
+    let fanout = &mut ctx.accounts.fanout;
+    assert_membership_model(fanout, MembershipModel::Wallet)?;
+    fanout.resignation_policy = resignation_policy;
+    emit!(FanoutUpdated {
+        fanout: fanout.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use crate::error::HydraError;
+use crate::events::FanoutUpdated;
+use crate::state::{Fanout, MembershipModel};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(root: Option<[u8; 32]>)]
+pub struct SetSharesRoot<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+    mut,
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    has_one = authority,
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+}
+
+// Leaves are keccak(membership key || shares as le bytes), see membership_leaf.
+pub fn set_shares_root(ctx: Context<SetSharesRoot>, root: Option<[u8; 32]>) -> Result<()> {
+    let fanout = &mut ctx.accounts.fanout;
+    if fanout.membership_model != MembershipModel::NFT
+        && fanout.membership_model != MembershipModel::Merkle
+    {
+        return Err(HydraError::InvalidMembershipModel.into());
+    }
+    fanout.shares_root = root;
+    emit!(FanoutUpdated {
+        fanout: fanout.key(),
+    });
+    Ok(())
+}
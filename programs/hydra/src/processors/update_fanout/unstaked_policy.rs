@@ -0,0 +1,30 @@
+use crate::events::FanoutUpdated;
+use crate::state::{Fanout, MembershipModel, UnstakedPolicy};
+use crate::utils::validation::assert_membership_model;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(unstaked_policy: UnstakedPolicy)]
+pub struct SetUnstakedPolicy<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+    mut,
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    has_one = authority,
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+}
+
+pub fn set_unstaked_policy(
+    ctx: Context<SetUnstakedPolicy>,
+    unstaked_policy: UnstakedPolicy,
+) -> Result<()> {
+    let fanout = &mut ctx.accounts.fanout;
+    assert_membership_model(fanout, MembershipModel::Token)?;
+    fanout.unstaked_policy = unstaked_policy;
+    emit!(FanoutUpdated {
+        fanout: fanout.key(),
+    });
+    Ok(())
+}
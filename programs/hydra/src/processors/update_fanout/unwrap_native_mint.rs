@@ -0,0 +1,83 @@
+use crate::error::{HydraError, OrArithError};
+use crate::events::FanoutUpdated;
+use crate::state::{Fanout, FanoutMembershipVoucher, FanoutMint};
+use crate::utils::validation::assert_owned_by;
+use crate::utils::{holding_account_amount, read_mint_membership_voucher};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(unwrap_native_mint: bool)]
+pub struct SetUnwrapNativeMint<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+    mut,
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    has_one = authority,
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+    #[account(
+    seeds = [b"fanout-config", fanout.key().as_ref(), spl_token::native_mint::id().as_ref()],
+    bump,
+    )]
+    /// CHECK: The native mint's FanoutMint, empty if wSOL was never registered
+    pub native_fanout_for_mint: UncheckedAccount<'info>,
+}
+
+// Once unwrapped, wSOL is accounted for as native inflow, so what the native FanoutMint still
+// holds for its members, reserve or treasury must be paid out first or it would be counted twice;
+// rounding dust left over becomes native inflow. Remaining accounts are the native mint's holding
+// account, then (membership voucher, mint membership voucher) for every member.
+pub fn set_unwrap_native_mint<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetUnwrapNativeMint<'info>>,
+    unwrap_native_mint: bool,
+) -> Result<()> {
+    let fanout = &mut ctx.accounts.fanout;
+    let native_fanout_for_mint = &ctx.accounts.native_fanout_for_mint;
+    if unwrap_native_mint && !native_fanout_for_mint.data_is_empty() {
+        assert_owned_by(native_fanout_for_mint, &crate::ID)?;
+        let mut data: &[u8] = &native_fanout_for_mint.try_borrow_data()?;
+        let fanout_for_mint = FanoutMint::try_deserialize(&mut data)?;
+        let (holding_account, member_accounts) = ctx
+            .remaining_accounts
+            .split_first()
+            .ok_or(HydraError::HoldingAccountRequired)?;
+        if holding_account_amount(&fanout_for_mint, holding_account)?
+            > fanout_for_mint.last_snapshot_amount
+            || fanout_for_mint.reserve_unclaimed > 0
+            || fanout_for_mint.treasury_unclaimed > 0
+        {
+            return Err(HydraError::NativeMintHasUnpaidInflow.into());
+        }
+        if member_accounts.len() as u64 != fanout.total_members.checked_mul(2).or_arith_error()? {
+            return Err(HydraError::MemberAccountsRequired.into());
+        }
+        let mut members: Vec<&Pubkey> = Vec::new();
+        for group in member_accounts.chunks(2) {
+            assert_owned_by(&group[0], &crate::ID)?;
+            let membership_voucher = {
+                let mut data: &[u8] = &group[0].try_borrow_data()?;
+                FanoutMembershipVoucher::try_deserialize(&mut data)?
+            };
+            if membership_voucher.fanout != fanout.key() || members.contains(&group[0].key) {
+                return Err(HydraError::InvalidMembershipVoucher.into());
+            }
+            members.push(group[0].key);
+            let mint_voucher = read_mint_membership_voucher(
+                &group[1],
+                native_fanout_for_mint.key,
+                &membership_voucher.membership_key,
+                &fanout_for_mint.mint,
+            )?;
+            let last_inflow = mint_voucher.map_or(0, |mint_voucher| mint_voucher.last_inflow);
+            if last_inflow != fanout_for_mint.total_inflow {
+                return Err(HydraError::NativeMintHasUnpaidInflow.into());
+            }
+        }
+    }
+    fanout.unwrap_native_mint = unwrap_native_mint;
+    emit!(FanoutUpdated {
+        fanout: fanout.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,152 @@
+use crate::error::HydraError;
+use crate::events::{MemberUpdated, MembershipExpired};
+use crate::state::{Fanout, FanoutMembershipVoucher, MembershipModel, HOLDING_ACCOUNT_SIZE};
+use crate::utils::logic::calculation::{
+    cancel_share_change, current_lamports, opens_inflow_window, schedule_expiry, update_inflow,
+    update_inflow_for_mint, update_share_schedule, update_share_schedule_for_mint,
+};
+use crate::utils::validation::assert_mints_ready_for_share_change;
+use crate::utils::{holding_account_amount, parse_fanout_mint_groups};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(expires_at: Option<i64>)]
+pub struct SetMemberExpiry<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+    mut,
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    has_one = authority,
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+    #[account(
+    mut,
+    seeds = [b"fanout-membership", fanout.key().as_ref(), membership_voucher.membership_key.as_ref()],
+    has_one = fanout,
+    bump = membership_voucher.bump_seed,
+    )]
+    pub membership_voucher: Account<'info, FanoutMembershipVoucher>,
+}
+
+// An expiry switches the member's shares to zero with the fanout's share schedule, so it has to
+// take effect together with any other scheduled change and the same mint accounts as a share
+// change are passed in the remaining accounts. The expired shares then follow the resignation
+// policy.
+pub fn set_member_expiry<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetMemberExpiry<'info>>,
+    expires_at: Option<i64>,
+) -> Result<()> {
+    let fanout = &mut ctx.accounts.fanout;
+    let membership_voucher = &mut ctx.accounts.membership_voucher;
+    if fanout.membership_model != MembershipModel::Wallet
+        && fanout.membership_model != MembershipModel::NFT
+    {
+        return Err(HydraError::InvalidMembershipModel.into());
+    }
+    let expiring = membership_voucher.expires_at.is_some();
+    match expires_at {
+        Some(expires_at) => {
+            // A pending share change would be dropped by the expiry
+            if membership_voucher.scheduled_shares.is_some() && !expiring {
+                return Err(HydraError::ShareChangePending.into());
+            }
+            if expires_at <= Clock::get()?.unix_timestamp {
+                return Err(HydraError::InvalidExpiry.into());
+            }
+            assert_mints_ready_for_share_change(
+                fanout,
+                &fanout.key(),
+                &membership_voucher.membership_key,
+                ctx.remaining_accounts,
+            )?;
+            schedule_expiry(fanout, membership_voucher, expires_at)?;
+        }
+        None if expiring => {
+            cancel_share_change(fanout, membership_voucher)?;
+            membership_voucher.expires_at = None;
+        }
+        None => {}
+    }
+    emit!(MemberUpdated {
+        fanout: fanout.key(),
+        membership_key: membership_voucher.membership_key,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReleaseExpiredMember<'info> {
+    #[account(
+    mut,
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+    #[account(
+    seeds = [b"fanout-membership", fanout.key().as_ref(), membership_voucher.membership_key.as_ref()],
+    has_one = fanout,
+    bump = membership_voucher.bump_seed,
+    )]
+    pub membership_voucher: Account<'info, FanoutMembershipVoucher>,
+    #[account(address = fanout.account_key)]
+    /// CHECK: Native holding account of the fanout
+    pub holding_account: UncheckedAccount<'info>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+// Expired shares switch at the first inflow update after the expiry, so anyone can snapshot the
+// fanout, natively and for every registered mint, for the switch to land at the expiry instead of
+// at the next distribution. Inside a used fanout scoped window the switch lands at the window's
+// snapshot. Remaining accounts are (fanout mint, holding account) for every registered mint.
+pub fn release_expired_member<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReleaseExpiredMember<'info>>,
+) -> Result<()> {
+    let fanout = &mut ctx.accounts.fanout;
+    let membership_voucher = &ctx.accounts.membership_voucher;
+    let expired = match membership_voucher.expires_at {
+        Some(expires_at) => Clock::get()?.unix_timestamp >= expires_at,
+        None => false,
+    };
+    if !expired || membership_voucher.scheduled_shares.is_none() {
+        return Err(HydraError::MembershipNotExpired.into());
+    }
+    let snapshots = opens_inflow_window(
+        fanout.distribution_window,
+        fanout.distribution_window_scope,
+        &mut fanout.last_distribution_window,
+    )?;
+    if snapshots {
+        let current_snapshot = current_lamports(
+            &ctx.accounts.rent,
+            HOLDING_ACCOUNT_SIZE,
+            ctx.accounts.holding_account.lamports(),
+        )?;
+        update_inflow(fanout, current_snapshot)?;
+    } else {
+        update_share_schedule(fanout)?;
+    }
+    let fanout_key = fanout.key();
+    let mint_groups = parse_fanout_mint_groups(fanout, &fanout_key, ctx.remaining_accounts, 2)?;
+    for (mut fanout_for_mint, group) in mint_groups {
+        let snapshots = opens_inflow_window(
+            fanout.distribution_window,
+            fanout.distribution_window_scope,
+            &mut fanout_for_mint.last_distribution_window,
+        )?;
+        if snapshots {
+            let current_snapshot = holding_account_amount(&fanout_for_mint, &group[1])?;
+            update_inflow_for_mint(fanout, &mut fanout_for_mint, current_snapshot)?;
+        } else {
+            update_share_schedule_for_mint(fanout, &mut fanout_for_mint)?;
+        }
+        let mut fanout_for_mint_data: &mut [u8] = &mut group[0].try_borrow_mut_data()?;
+        fanout_for_mint.try_serialize(&mut fanout_for_mint_data)?;
+    }
+    emit!(MembershipExpired {
+        fanout: fanout.key(),
+        membership_key: membership_voucher.membership_key,
+        shares: membership_voucher.shares,
+    });
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use crate::error::HydraError;
+use crate::events::MemberUpdated;
+use crate::state::{
+    Fanout, FanoutMembershipVoucher, FANOUT_MEMBERSHIP_VOUCHER_SIZE, MAX_MEMBER_LABEL_LENGTH,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(label: Option<String>)]
+pub struct SetMemberLabel<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    has_one = authority,
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+    #[account(
+    mut,
+    seeds = [b"fanout-membership", fanout.key().as_ref(), membership_voucher.membership_key.as_ref()],
+    has_one = fanout,
+    bump = membership_voucher.bump_seed,
+    )]
+    pub membership_voucher: Account<'info, FanoutMembershipVoucher>,
+}
+
+// Vouchers created before labels existed have no room for one until they are migrated.
+pub fn set_member_label(ctx: Context<SetMemberLabel>, label: Option<String>) -> Result<()> {
+    let fanout = &ctx.accounts.fanout;
+    let membership_voucher = &mut ctx.accounts.membership_voucher;
+    if let Some(label) = &label {
+        if label.len() > MAX_MEMBER_LABEL_LENGTH {
+            return Err(HydraError::LabelTooLong.into());
+        }
+    }
+    if membership_voucher.to_account_info().data_len() < FANOUT_MEMBERSHIP_VOUCHER_SIZE {
+        return Err(HydraError::AccountOutdated.into());
+    }
+    membership_voucher.label = label;
+    emit!(MemberUpdated {
+        fanout: fanout.key(),
+        membership_key: membership_voucher.membership_key,
+    });
+    Ok(())
+}
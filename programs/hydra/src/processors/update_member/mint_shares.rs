@@ -0,0 +1,90 @@
+use crate::error::{HydraError, OrArithError};
+use crate::events::MemberUpdated;
+use crate::state::{Fanout, FanoutMembershipVoucher, FanoutMint};
+use crate::utils::logic::calculation::update_inflow_for_mint;
+use crate::utils::parse_mint_membership_voucher;
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+#[derive(Accounts)]
+#[instruction(shares: u64)]
+pub struct SetMintMemberShares<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+    mut,
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    has_one = authority,
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+    #[account(
+    mut,
+    seeds = [b"fanout-config", fanout.key().as_ref(), fanout_for_mint.mint.as_ref()],
+    has_one = fanout,
+    bump = fanout_for_mint.bump_seed,
+    )]
+    pub fanout_for_mint: Account<'info, FanoutMint>,
+    #[account(address = fanout_for_mint.token_account)]
+    pub holding_account: Account<'info, TokenAccount>,
+    #[account(
+    seeds = [b"fanout-membership", fanout.key().as_ref(), membership_voucher.membership_key.as_ref()],
+    has_one = fanout,
+    bump = membership_voucher.bump_seed,
+    )]
+    pub membership_voucher: Account<'info, FanoutMembershipVoucher>,
+    #[account(mut)]
+    /// CHECK: Checked in program
+    pub fanout_for_mint_membership_voucher: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+// A member's recorded mint inflow is settled at the old shares before they change, and the
+// mint's inflow is snapshotted so it is split by the table it arrived under.
+pub fn set_mint_member_shares(ctx: Context<SetMintMemberShares>, shares: u64) -> Result<()> {
+    let fanout = &mut ctx.accounts.fanout;
+    let fanout_for_mint = &mut ctx.accounts.fanout_for_mint;
+    let membership_key = ctx.accounts.membership_voucher.membership_key;
+    if fanout_for_mint.total_shares.is_none() {
+        return Err(HydraError::ShareTableRequired.into());
+    }
+    update_inflow_for_mint(fanout, fanout_for_mint, ctx.accounts.holding_account.amount)?;
+    let fanout_for_mint_key = fanout_for_mint.key();
+    let mut mint_voucher = parse_mint_membership_voucher(
+        &mut ctx.accounts.fanout_for_mint_membership_voucher,
+        &ctx.accounts.rent,
+        &ctx.accounts.system_program,
+        &ctx.accounts.authority.to_account_info(),
+        &membership_key,
+        &fanout_for_mint_key,
+        &fanout_for_mint.mint,
+        &fanout.key(),
+        fanout_for_mint.total_inflow,
+    )?;
+    if mint_voucher.shares > 0 && mint_voucher.last_inflow != fanout_for_mint.total_inflow {
+        return Err(HydraError::MustDistribute.into());
+    }
+    // Without shares nothing was earned from the table, so new shares start from here
+    if mint_voucher.shares == 0 {
+        mint_voucher.last_inflow = fanout_for_mint.total_inflow;
+        mint_voucher.share_epoch = fanout_for_mint.share_epoch;
+    }
+    fanout_for_mint.total_available_shares = fanout_for_mint
+        .total_available_shares
+        .checked_add(mint_voucher.shares)
+        .or_arith_error()?
+        .checked_sub(shares)
+        .ok_or(HydraError::InsufficientShares)?;
+    mint_voucher.shares = shares;
+    let mut mint_voucher_data: &mut [u8] = &mut ctx
+        .accounts
+        .fanout_for_mint_membership_voucher
+        .try_borrow_mut_data()?;
+    mint_voucher.try_serialize(&mut mint_voucher_data)?;
+    emit!(MemberUpdated {
+        fanout: fanout.key(),
+        membership_key,
+    });
+    Ok(())
+}
@@ -0,0 +1,7 @@
+pub mod expiry;
+pub mod label;
+pub mod mint_shares;
+pub mod payout_destination;
+pub mod resign;
+pub mod rotate_key;
+pub mod share_schedule;
@@ -0,0 +1,40 @@
+use crate::error::HydraError;
+use crate::events::MemberUpdated;
+use crate::state::{Fanout, FanoutMembershipVoucher, MembershipModel};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(payout_destination: Option<Pubkey>)]
+pub struct SetPayoutDestination<'info> {
+    pub member: Signer<'info>,
+    #[account(
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+    #[account(
+    mut,
+    seeds = [b"fanout-membership", fanout.key().as_ref(), member.key().as_ref()],
+    constraint = membership_voucher.membership_key == member.key(),
+    bump = membership_voucher.bump_seed,
+    )]
+    pub membership_voucher: Account<'info, FanoutMembershipVoucher>,
+}
+
+pub fn set_payout_destination(
+    ctx: Context<SetPayoutDestination>,
+    payout_destination: Option<Pubkey>,
+) -> Result<()> {
+    let fanout = &ctx.accounts.fanout;
+    let membership_voucher = &mut ctx.accounts.membership_voucher;
+    // NFT vouchers outlive their holder, so a destination would survive a transfer of the NFT
+    if fanout.membership_model == MembershipModel::NFT {
+        return Err(HydraError::InvalidMembershipModel.into());
+    }
+    membership_voucher.payout_destination = payout_destination;
+    emit!(MemberUpdated {
+        fanout: fanout.key(),
+        membership_key: membership_voucher.membership_key,
+    });
+    Ok(())
+}
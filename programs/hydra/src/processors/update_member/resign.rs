@@ -0,0 +1,100 @@
+use crate::error::{HydraError, OrArithError};
+use crate::events::MemberRemoved;
+use crate::state::{Fanout, FanoutMembershipVoucher, MembershipModel};
+use crate::utils::logic::calculation::{
+    cancel_share_change, release_shares, update_fanout_for_remove,
+};
+use crate::utils::validation::{
+    assert_distributed, assert_membership_model, assert_mint_settled, assert_settled,
+};
+use crate::utils::{
+    close_account_raw, holding_account_amount, parse_fanout_mint_groups,
+    read_mint_membership_voucher,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::solana_program::sysvar::instructions::get_instruction_relative;
+
+#[derive(Accounts)]
+pub struct Resign<'info> {
+    #[account(mut)]
+    pub member: Signer<'info>,
+    #[account(
+    mut,
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+    #[account(
+    mut,
+    close = rent_payer,
+    seeds = [b"fanout-membership", fanout.key().as_ref(), member.key().as_ref()],
+    constraint = membership_voucher.membership_key == member.key(),
+    bump = membership_voucher.bump_seed,
+    )]
+    pub membership_voucher: Account<'info, FanoutMembershipVoucher>,
+    #[account(
+    mut,
+    constraint = rent_payer.key() == membership_voucher.rent_payer.unwrap_or(fanout.authority) @ HydraError::InvalidRentPayer,
+    )]
+    /// CHECK: Checked against the voucher
+    pub rent_payer: UncheckedAccount<'info>,
+    #[account(address = sysvar::instructions::id())]
+    /// CHECK: Instructions SYSVAR
+    pub instructions: UncheckedAccount<'info>,
+}
+
+// Vouchers from before rent payers were recorded were paid for by the authority. Remaining
+// accounts are (fanout mint, holding account, mint membership voucher) for every registered mint;
+// the member must be settled on each, shares held in a mint's own share table go back to it and
+// the mint vouchers are closed to the member.
+pub fn resign<'info>(ctx: Context<'_, '_, '_, 'info, Resign<'info>>) -> Result<()> {
+    let fanout = &mut ctx.accounts.fanout;
+    let member = &ctx.accounts.member;
+    let membership_voucher = &mut ctx.accounts.membership_voucher;
+    assert_membership_model(fanout, MembershipModel::Wallet)?;
+    if membership_voucher.shares > 0 {
+        let prev_ix = get_instruction_relative(-1, &ctx.accounts.instructions).unwrap();
+        assert_distributed(prev_ix, member.key, fanout.membership_model)?;
+        assert_settled(fanout, membership_voucher)?;
+    }
+    let fanout_key = fanout.key();
+    let mint_groups = parse_fanout_mint_groups(fanout, &fanout_key, ctx.remaining_accounts, 3)?;
+    for (mut fanout_for_mint, group) in mint_groups {
+        let mint_voucher = read_mint_membership_voucher(
+            &group[2],
+            group[0].key,
+            member.key,
+            &fanout_for_mint.mint,
+        )?;
+        assert_mint_settled(&fanout_for_mint, &group[1], mint_voucher.as_ref())?;
+        let mint_voucher = match mint_voucher {
+            Some(mint_voucher) => mint_voucher,
+            None => continue,
+        };
+        if mint_voucher.shares > 0 {
+            let holding_amount = holding_account_amount(&fanout_for_mint, &group[1])?;
+            if holding_amount > fanout_for_mint.last_snapshot_amount
+                || mint_voucher.last_inflow != fanout_for_mint.total_inflow
+            {
+                return Err(HydraError::MustDistribute.into());
+            }
+            fanout_for_mint.total_available_shares = fanout_for_mint
+                .total_available_shares
+                .checked_add(mint_voucher.shares)
+                .or_arith_error()?;
+            let mut fanout_for_mint_data: &mut [u8] = &mut group[0].try_borrow_mut_data()?;
+            fanout_for_mint.try_serialize(&mut fanout_for_mint_data)?;
+        }
+        close_account_raw(&group[2], &member.to_account_info())?;
+    }
+    cancel_share_change(fanout, membership_voucher)?;
+    release_shares(fanout, membership_voucher.shares)?;
+    update_fanout_for_remove(fanout)?;
+    emit!(MemberRemoved {
+        fanout: fanout.key(),
+        membership_key: membership_voucher.membership_key,
+        membership_voucher: membership_voucher.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use crate::error::HydraError;
+use crate::events::MemberKeyRotated;
+use crate::state::{
+    Fanout, FanoutMembershipVoucher, MembershipModel, ACCOUNT_VERSION,
+    FANOUT_MEMBERSHIP_VOUCHER_SIZE, FANOUT_MINT_MEMBERSHIP_VOUCHER_SIZE,
+};
+use crate::utils::validation::{assert_derivation, assert_membership_model};
+use crate::utils::{
+    close_account_raw, create_or_allocate_account_raw, parse_fanout_mint_groups,
+    read_mint_membership_voucher,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RotateMemberKey<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    /// CHECK: Checked in program
+    pub member: UncheckedAccount<'info>,
+    /// CHECK: Any account can become the member key
+    pub new_member: UncheckedAccount<'info>,
+    #[account(
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+    #[account(
+    mut,
+    close = rent_payer,
+    seeds = [b"fanout-membership", fanout.key().as_ref(), member.key().as_ref()],
+    constraint = membership_voucher.membership_key == member.key(),
+    bump = membership_voucher.bump_seed,
+    )]
+    pub membership_voucher: Account<'info, FanoutMembershipVoucher>,
+    #[account(
+    init,
+    space = FANOUT_MEMBERSHIP_VOUCHER_SIZE,
+    seeds = [b"fanout-membership", fanout.key().as_ref(), new_member.key().as_ref()],
+    bump,
+    payer = signer
+    )]
+    pub new_membership_voucher: Account<'info, FanoutMembershipVoucher>,
+    #[account(
+    mut,
+    constraint = rent_payer.key() == membership_voucher.rent_payer.unwrap_or(fanout.authority) @ HydraError::InvalidRentPayer,
+    )]
+    /// CHECK: Checked against the voucher
+    pub rent_payer: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+// Remaining accounts are (fanout mint, mint membership voucher, new mint membership voucher) for
+// every registered mint, so no mint inflow the old key claimed can be claimed again by the new key.
+// The signer pays for the new vouchers. The old membership voucher is closed to whoever paid its
+// rent, while the old mint vouchers, whose payer is not recorded, are closed to the signer.
+pub fn rotate_member_key<'info>(
+    ctx: Context<'_, '_, '_, 'info, RotateMemberKey<'info>>,
+) -> Result<()> {
+    let fanout = &ctx.accounts.fanout;
+    let signer = &ctx.accounts.signer;
+    let member = &ctx.accounts.member;
+    let new_member = &ctx.accounts.new_member;
+    let membership_voucher = &ctx.accounts.membership_voucher;
+    assert_membership_model(fanout, MembershipModel::Wallet)?;
+    if signer.key() != member.key() && signer.key() != fanout.authority {
+        return Err(HydraError::InvalidRotationSigner.into());
+    }
+    let new_membership_voucher = &mut ctx.accounts.new_membership_voucher;
+    new_membership_voucher.fanout = fanout.key();
+    new_membership_voucher.total_inflow = membership_voucher.total_inflow;
+    new_membership_voucher.last_inflow = membership_voucher.last_inflow;
+    new_membership_voucher.bump_seed = *ctx.bumps.get("new_membership_voucher").unwrap();
+    new_membership_voucher.version = ACCOUNT_VERSION;
+    new_membership_voucher.membership_key = new_member.key();
+    new_membership_voucher.shares = membership_voucher.shares;
+    new_membership_voucher.payout_destination = membership_voucher.payout_destination;
+    new_membership_voucher.rent_payer = Some(signer.key());
+    new_membership_voucher.expires_at = membership_voucher.expires_at;
+    new_membership_voucher.scheduled_shares = membership_voucher.scheduled_shares;
+    new_membership_voucher.share_epoch = membership_voucher.share_epoch;
+    new_membership_voucher.previous_shares = membership_voucher.previous_shares;
+    new_membership_voucher.label = membership_voucher.label.clone();
+    new_membership_voucher.last_distribution_window = membership_voucher.last_distribution_window;
+
+    let mint_groups = parse_fanout_mint_groups(fanout, &fanout.key(), ctx.remaining_accounts, 3)?;
+    for (fanout_for_mint_object, group) in mint_groups {
+        let (fanout_for_mint, mint_voucher_info, new_mint_voucher_info) =
+            (group[0].key(), &group[1], &group[2]);
+        let mint = fanout_for_mint_object.mint;
+        let mut mint_voucher = match read_mint_membership_voucher(
+            mint_voucher_info,
+            &fanout_for_mint,
+            member.key,
+            &mint,
+        )? {
+            Some(mint_voucher) => mint_voucher,
+            None => continue,
+        };
+        let new_bump = assert_derivation(
+            &crate::ID,
+            new_mint_voucher_info,
+            &[
+                b"fanout-membership",
+                fanout_for_mint.as_ref(),
+                new_member.key.as_ref(),
+                mint.as_ref(),
+            ],
+            Some(HydraError::InvalidMembershipVoucher.into()),
+        )?;
+        if !new_mint_voucher_info.data_is_empty() {
+            return Err(HydraError::InvalidMembershipVoucher.into());
+        }
+        create_or_allocate_account_raw(
+            crate::ID,
+            new_mint_voucher_info,
+            &ctx.accounts.rent.to_account_info(),
+            &ctx.accounts.system_program,
+            &signer.to_account_info(),
+            FANOUT_MINT_MEMBERSHIP_VOUCHER_SIZE,
+            &[],
+            &[
+                b"fanout-membership",
+                fanout_for_mint.as_ref(),
+                new_member.key.as_ref(),
+                mint.as_ref(),
+                &[new_bump],
+            ],
+        )?;
+        mint_voucher.bump_seed = new_bump;
+        let mut new_mint_voucher_data: &mut [u8] =
+            &mut new_mint_voucher_info.try_borrow_mut_data()?;
+        mint_voucher.try_serialize(&mut new_mint_voucher_data)?;
+        close_account_raw(mint_voucher_info, &signer.to_account_info())?;
+    }
+    emit!(MemberKeyRotated {
+        fanout: fanout.key(),
+        membership_key: member.key(),
+        new_membership_key: new_member.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use crate::error::HydraError;
+use crate::events::MemberUpdated;
+use crate::state::{Fanout, FanoutMembershipVoucher, MembershipModel};
+use crate::utils::logic::calculation::schedule_share_change;
+use crate::utils::validation::assert_mints_ready_for_share_change;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(shares: u64, effective_at: i64)]
+pub struct ScheduleShareChange<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+    mut,
+    seeds = [b"fanout-config", fanout.base_seed(), fanout.name.as_bytes()],
+    has_one = authority,
+    bump = fanout.bump_seed,
+    )]
+    pub fanout: Account<'info, Fanout>,
+    #[account(
+    mut,
+    seeds = [b"fanout-membership", fanout.key().as_ref(), membership_voucher.membership_key.as_ref()],
+    has_one = fanout,
+    bump = membership_voucher.bump_seed,
+    )]
+    pub membership_voucher: Account<'info, FanoutMembershipVoucher>,
+}
+
+// All changes in a schedule switch together, and a new schedule can only start once every
+// member has distributed past the previous switch, natively and for every mint. Remaining
+// accounts are (fanout mint, mint membership voucher) for every registered mint.
+pub fn schedule_member_share_change<'info>(
+    ctx: Context<'_, '_, '_, 'info, ScheduleShareChange<'info>>,
+    shares: u64,
+    effective_at: i64,
+) -> Result<()> {
+    let fanout = &mut ctx.accounts.fanout;
+    let membership_voucher = &mut ctx.accounts.membership_voucher;
+    if fanout.membership_model != MembershipModel::Wallet
+        && fanout.membership_model != MembershipModel::NFT
+    {
+        return Err(HydraError::InvalidMembershipModel.into());
+    }
+    if effective_at <= Clock::get()?.unix_timestamp {
+        return Err(HydraError::InvalidShareSchedule.into());
+    }
+    assert_mints_ready_for_share_change(
+        fanout,
+        &fanout.key(),
+        &membership_voucher.membership_key,
+        ctx.remaining_accounts,
+    )?;
+    schedule_share_change(fanout, membership_voucher, shares, effective_at)?;
+    emit!(MemberUpdated {
+        fanout: fanout.key(),
+        membership_key: membership_voucher.membership_key,
+    });
+    Ok(())
+}
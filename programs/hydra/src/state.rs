@@ -3,11 +3,20 @@ use std::default::Default;
 
 pub const HOLDING_ACCOUNT_SIZE: usize = 1;
 
+// Bumped whenever a layout outgrows the space allocated for it; smaller accounts are grown
+// with the migrate_account instruction, fields that fit the existing space need no bump.
+pub const ACCOUNT_VERSION: u8 = 3;
+pub const FANOUT_SIZE: usize = 600;
+pub const FANOUT_MINT_SIZE: usize = 300;
+// A crank reward never takes more than this share of a payout
+pub const MAX_CRANK_REWARD_BASIS_POINTS: u16 = 1000;
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Copy, Debug)]
 pub enum MembershipModel {
     Wallet = 0,
     Token = 1,
     NFT = 2,
+    Merkle = 3,
 }
 
 impl Default for MembershipModel {
@@ -16,37 +25,190 @@ impl Default for MembershipModel {
     }
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Copy, Debug)]
+pub enum CrankReward {
+    Fixed {
+        lamports: u64,
+    },
+    BasisPoints {
+        basis_points: u16,
+        max_lamports: u64,
+    },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Copy, Debug)]
+pub enum UnstakedPolicy {
+    Redistribute = 0,
+    Treasury = 1,
+    RollForward = 2,
+}
+
+impl Default for UnstakedPolicy {
+    fn default() -> Self {
+        UnstakedPolicy::Redistribute
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Copy, Debug)]
+pub enum ResignationPolicy {
+    ReturnToPool = 0,
+    Redistribute = 1,
+}
+
+impl Default for ResignationPolicy {
+    fn default() -> Self {
+        ResignationPolicy::ReturnToPool
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Copy, Debug)]
+pub enum DistributionWindow {
+    Epochs { epochs: u64 },
+    Seconds { seconds: u64 },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Copy, Debug)]
+pub enum DistributionWindowScope {
+    Fanout = 0,
+    Member = 1,
+}
+
+impl Default for DistributionWindowScope {
+    fn default() -> Self {
+        DistributionWindowScope::Fanout
+    }
+}
+
 #[account]
 #[derive(Default, Debug)]
 pub struct Fanout {
-    pub authority: Pubkey,                 //32
-    pub name: String,                      //50
-    pub account_key: Pubkey,               //32
-    pub total_shares: u64,                 //8
-    pub total_members: u64,                //8
-    pub total_inflow: u64,                 //8
-    pub last_snapshot_amount: u64,         //8
-    pub bump_seed: u8,                     //1
-    pub account_owner_bump_seed: u8,       //1
-    pub total_available_shares: u64,       //8
-    pub membership_model: MembershipModel, //1
-    pub membership_mint: Option<Pubkey>,   //32
-    pub total_staked_shares: Option<u64>,  //4
+    pub authority: Pubkey,                                  //32
+    pub name: String,                                       //50
+    pub account_key: Pubkey,                                //32
+    pub total_shares: u64,                                  //8
+    pub total_members: u64,                                 //8
+    pub total_inflow: u64,                                  //8
+    pub last_snapshot_amount: u64,                          //8
+    pub bump_seed: u8,                                      //1
+    pub account_owner_bump_seed: u8,                        //1
+    pub total_available_shares: u64,                        //8
+    pub membership_model: MembershipModel,                  //1
+    pub membership_mint: Option<Pubkey>,                    //32
+    pub total_staked_shares: Option<u64>,                   //4
+    pub unwrap_native_mint: bool,                           //1
+    pub fee_basis_points: u16,                              //2
+    pub fee_recipient: Option<Pubkey>,                      //33
+    pub total_fees: u64,                                    //8
+    pub crank_reward: Option<CrankReward>,                  //12
+    pub total_crank_rewards: u64,                           //8
+    pub membership_collection: Option<Pubkey>,              //33
+    pub shares_per_nft: u64,                                //8
+    pub shares_root: Option<[u8; 32]>,                      //33
+    pub reserve: Option<Pubkey>,                            //33
+    pub reserve_unclaimed: u64,                             //8
+    pub unstaked_policy: UnstakedPolicy,                    //1
+    pub treasury_unclaimed: u64,                            //8
+    pub membership_supply: u64,                             //8
+    pub supply_checkpoint: u64,                             //8
+    pub weighted_mint_supply: u64,                          //8
+    pub member_transfers_enabled: bool,                     //1
+    pub resignation_policy: ResignationPolicy,              //1
+    pub retired_shares: u64,                                //8
+    pub shares_released: bool,                              //1
+    pub share_schedule_at: Option<i64>,                     //9
+    pub share_epoch: u64,                                   //8
+    pub share_switch_inflow: u64,                           //8
+    pub scheduled_share_delta: i64,                         //8
+    pub pending_share_changes: u64,                         //8
+    pub base: Option<Pubkey>,                               //33
+    pub version: u8,                                        //1
+    pub min_distribution: u64,                              //8
+    pub distribution_window: Option<DistributionWindow>,    //10
+    pub distribution_window_scope: DistributionWindowScope, //1
+    pub last_distribution_window: Option<u64>,              //9
+    pub mints_with_reserve: u32,                            //4
+    pub total_mints: u64,                                   //8
+    pub share_switch_changes: u64,                          //8
+    pub expiring_shares: u64,                               //8
+}
+
+impl Fanout {
+    // Fanouts created before base keys were seeded by name alone; an empty seed derives the
+    // same address as leaving it out.
+    pub fn base_seed(&self) -> &[u8] {
+        match &self.base {
+            Some(base) => base.as_ref(),
+            None => &[],
+        }
+    }
 }
 
 #[account]
 #[derive(Default, Debug)]
 pub struct FanoutMint {
-    pub mint: Pubkey,              //32
-    pub fanout: Pubkey,            //32
-    pub token_account: Pubkey,     //32
-    pub total_inflow: u64,         //8
-    pub last_snapshot_amount: u64, //8
-    pub bump_seed: u8,             //1
-                                   // +50 padding
+    pub mint: Pubkey,                          //32
+    pub fanout: Pubkey,                        //32
+    pub token_account: Pubkey,                 //32
+    pub total_inflow: u64,                     //8
+    pub last_snapshot_amount: u64,             //8
+    pub bump_seed: u8,                         //1
+    pub total_fees: u64,                       //8
+    pub reserve_unclaimed: u64,                //8
+    pub treasury_unclaimed: u64,               //8
+    pub supply_checkpoint: u64,                //8
+    pub share_epoch: u64,                      //8
+    pub share_switch_inflow: u64,              //8
+    pub total_shares: Option<u64>,             //9
+    pub total_available_shares: u64,           //8
+    pub version: u8,                           //1
+    pub min_distribution: u64,                 //8
+    pub counted: bool,                         //1
+    pub pending_share_changes: u32,            //4
+    pub last_distribution_window: Option<u64>, //9
+}
+
+pub const FANOUT_MEMBERSHIP_MINT_SIZE: usize = 32 + 32 + 8 + 8 + 8 + 1 + 1 + 38;
+#[account]
+#[derive(Default, Debug)]
+pub struct FanoutMembershipMint {
+    pub fanout: Pubkey,
+    pub mint: Pubkey,
+    pub weight: u64,
+    pub total_staked: u64,
+    pub supply: u64,
+    pub bump_seed: u8,
+    pub version: u8,
+}
+
+pub const FANOUT_MEMBERSHIP_STAKE_SIZE: usize = 32 + 32 + 32 + 8 + 1 + 1 + 30;
+#[account]
+#[derive(Default, Debug)]
+pub struct FanoutMembershipStake {
+    pub fanout: Pubkey,
+    pub membership_key: Pubkey,
+    pub membership_mint: Pubkey,
+    pub amount: u64,
+    pub bump_seed: u8,
+    pub version: u8,
 }
 
-pub const FANOUT_MEMBERSHIP_VOUCHER_SIZE: usize = 32 + 8 + 8 + 1 + 32 + 8 + 64;
+pub const MAX_MEMBER_LABEL_LENGTH: usize = 32;
+pub const FANOUT_MEMBERSHIP_VOUCHER_SIZE: usize = 32
+    + 8
+    + 8
+    + 1
+    + 32
+    + 8
+    + 33
+    + 33
+    + 9
+    + 9
+    + 8
+    + 8
+    + 1
+    + 5
+    + MAX_MEMBER_LABEL_LENGTH
+    + 31;
 #[account]
 #[derive(Default, Debug)]
 pub struct FanoutMembershipVoucher {
@@ -56,9 +218,19 @@ pub struct FanoutMembershipVoucher {
     pub bump_seed: u8,
     pub membership_key: Pubkey,
     pub shares: u64,
+    pub payout_destination: Option<Pubkey>,
+    pub rent_payer: Option<Pubkey>,
+    pub expires_at: Option<i64>,
+    pub scheduled_shares: Option<u64>,
+    pub share_epoch: u64,
+    pub previous_shares: u64,
+    pub version: u8,
+    pub label: Option<String>,
+    pub last_distribution_window: Option<u64>,
 }
 
-pub const FANOUT_MINT_MEMBERSHIP_VOUCHER_SIZE: usize = 32 + 32 + 8 + 1 + 32;
+pub const FANOUT_MINT_MEMBERSHIP_VOUCHER_SIZE: usize =
+    32 + 32 + 8 + 1 + 8 + 1 + 8 + 8 + 1 + 9 + 21;
 #[account]
 #[derive(Default)]
 pub struct FanoutMembershipMintVoucher {
@@ -66,4 +238,24 @@ pub struct FanoutMembershipMintVoucher {
     pub fanout_mint: Pubkey,
     pub last_inflow: u64,
     pub bump_seed: u8,
+    pub total_inflow: u64,
+    pub total_inflow_migrated: bool,
+    pub share_epoch: u64,
+    pub shares: u64,
+    pub version: u8,
+    pub last_distribution_window: Option<u64>,
+}
+
+pub const MAX_METADATA_URI_LENGTH: usize = 200;
+pub const MAX_METADATA_DESCRIPTION_LENGTH: usize = 128;
+pub const FANOUT_METADATA_SIZE: usize =
+    32 + 4 + MAX_METADATA_URI_LENGTH + 4 + MAX_METADATA_DESCRIPTION_LENGTH + 1 + 1 + 30;
+#[account]
+#[derive(Default, Debug)]
+pub struct FanoutMetadata {
+    pub fanout: Pubkey,
+    pub uri: String,
+    pub description: String,
+    pub bump_seed: u8,
+    pub version: u8,
 }
@@ -1,6 +1,11 @@
 use crate::error::{HydraError, OrArithError};
-use crate::state::{Fanout, FanoutMembershipMintVoucher, FanoutMembershipVoucher, FanoutMint};
+use crate::state::{
+    CrankReward, DistributionWindow, DistributionWindowScope, Fanout, FanoutMembershipMint,
+    FanoutMembershipMintVoucher, FanoutMembershipVoucher, FanoutMint, ResignationPolicy,
+    UnstakedPolicy, HOLDING_ACCOUNT_SIZE, MAX_CRANK_REWARD_BASIS_POINTS,
+};
 use anchor_lang::prelude::*;
+use std::convert::TryInto;
 
 pub fn calculate_inflow_change(total_inflow: u64, last_inflow: u64) -> Result<u64> {
     let diff: u64 = total_inflow.checked_sub(last_inflow).or_arith_error()?;
@@ -23,6 +28,40 @@ pub fn calculate_dist_amount(
     Ok(dist_amount as u64)
 }
 
+pub fn calculate_fee(amount: u64, fee_basis_points: u16) -> Result<u64> {
+    let fee = (amount as u128)
+        .checked_mul(fee_basis_points as u128)
+        .or_arith_error()?
+        .checked_div(10000)
+        .or_arith_error()?;
+    Ok(fee as u64)
+}
+
+pub fn calculate_crank_reward(crank_reward: Option<CrankReward>, amount: u64) -> Result<u64> {
+    let reward = match crank_reward {
+        None => 0,
+        Some(CrankReward::Fixed { lamports }) => lamports,
+        Some(CrankReward::BasisPoints {
+            basis_points,
+            max_lamports,
+        }) => calculate_fee(amount, basis_points)?.min(max_lamports),
+    };
+    // Capped so small payouts still reach the member
+    let max_reward = calculate_fee(amount, MAX_CRANK_REWARD_BASIS_POINTS)?;
+    Ok(reward.min(max_reward))
+}
+
+// Fixed rewards are in lamports, so only basis point rewards apply to mint distributions.
+pub fn calculate_mint_crank_reward(crank_reward: Option<CrankReward>, amount: u64) -> Result<u64> {
+    match crank_reward {
+        Some(CrankReward::BasisPoints { basis_points, .. }) => {
+            let max_reward = calculate_fee(amount, MAX_CRANK_REWARD_BASIS_POINTS)?;
+            Ok(calculate_fee(amount, basis_points)?.min(max_reward))
+        }
+        _ => Ok(0),
+    }
+}
+
 pub fn update_fanout_for_add(
     fanout: &mut Account<Fanout>,
     shares: u64,
@@ -40,6 +79,510 @@ pub fn update_fanout_for_add(
     }
 }
 
+// Members added while a reserve holds the unallocated shares, or into shares released by
+// another member, must not claim inflow already accrued, so they start from the current inflow.
+pub fn joins_late(fanout: &Fanout) -> bool {
+    fanout.reserve.is_some() || fanout.shares_released
+}
+
+// Inflow held for unallocated shares is split off when the holding account is snapshotted, so once
+// members join late the holding account, passed first in the remaining accounts, is snapshotted
+// before shares are allocated.
+pub fn update_inflow_for_add(
+    fanout: &mut Fanout,
+    remaining_accounts: &[AccountInfo],
+    rent: &Sysvar<Rent>,
+) -> Result<()> {
+    if !joins_late(fanout) {
+        return Ok(());
+    }
+    let holding_account = remaining_accounts
+        .first()
+        .filter(|account| account.key == &fanout.account_key)
+        .ok_or(HydraError::HoldingAccountRequired)?;
+    let current_snapshot =
+        current_lamports(rent, HOLDING_ACCOUNT_SIZE, holding_account.lamports())?;
+    update_inflow(fanout, current_snapshot)
+}
+
+pub fn update_voucher_for_add(fanout: &Fanout, voucher: &mut FanoutMembershipVoucher) {
+    if joins_late(fanout) {
+        voucher.last_inflow = fanout.total_inflow;
+    }
+}
+
+// Shares given up by a member follow the fanout's resignation policy.
+pub fn release_shares(fanout: &mut Fanout, shares: u64) -> Result<()> {
+    match fanout.resignation_policy {
+        ResignationPolicy::ReturnToPool => {
+            fanout.total_available_shares = fanout
+                .total_available_shares
+                .checked_add(shares)
+                .or_arith_error()?;
+            fanout.shares_released = true;
+        }
+        ResignationPolicy::Redistribute => {
+            fanout.retired_shares = fanout
+                .retired_shares
+                .checked_add(shares)
+                .or_arith_error()?;
+        }
+    }
+    Ok(())
+}
+
+// A scheduled share table switches at the first inflow update after it takes effect, so
+// inflow observed up to then is settled at the old shares.
+pub fn update_share_schedule(fanout: &mut Fanout) -> Result<()> {
+    match fanout.share_schedule_at {
+        Some(effective_at) if Clock::get()?.unix_timestamp >= effective_at => {}
+        _ => return Ok(()),
+    }
+    let total_available_shares = (fanout.total_available_shares as i128)
+        .checked_sub(fanout.scheduled_share_delta as i128)
+        .or_arith_error()?;
+    if total_available_shares < 0 {
+        return Err(HydraError::InsufficientShares.into());
+    }
+    fanout.total_available_shares = total_available_shares as u64;
+    fanout.scheduled_share_delta = 0;
+    // Shares of memberships expiring at the switch follow the resignation policy
+    if fanout.expiring_shares > 0 {
+        release_shares(fanout, fanout.expiring_shares)?;
+        fanout.expiring_shares = 0;
+    }
+    fanout.share_schedule_at = None;
+    fanout.share_epoch = fanout.share_epoch.checked_add(1).or_arith_error()?;
+    fanout.share_switch_inflow = fanout.total_inflow;
+    fanout.share_switch_changes = fanout.pending_share_changes;
+    Ok(())
+}
+
+pub fn update_share_schedule_for_mint(
+    fanout: &mut Fanout,
+    fanout_mint: &mut FanoutMint,
+) -> Result<()> {
+    update_share_schedule(fanout)?;
+    if fanout_mint.share_epoch < fanout.share_epoch {
+        fanout_mint.share_epoch = fanout.share_epoch;
+        fanout_mint.share_switch_inflow = fanout_mint.total_inflow;
+        // Each member changed at the switch settles this mint across it before the next schedule,
+        // unless the mint pays by its own share table
+        fanout_mint.pending_share_changes = if fanout_mint.total_shares.is_some() {
+            0
+        } else {
+            fanout
+                .share_switch_changes
+                .try_into()
+                .map_err(|_| HydraError::NumericalOverflow)?
+        };
+    }
+    Ok(())
+}
+
+// Counts a member changed at the mint's current epoch as settled once its mint voucher, last
+// settled at settled_epoch or just created, crosses the switch.
+pub fn settle_mint_share_change(
+    fanout_mint: &mut FanoutMint,
+    voucher: &FanoutMembershipVoucher,
+    settled_epoch: u64,
+    created: bool,
+) {
+    if fanout_mint.total_shares.is_none()
+        && voucher.share_epoch > 0
+        && voucher.share_epoch == fanout_mint.share_epoch
+        && (created || settled_epoch < fanout_mint.share_epoch)
+    {
+        fanout_mint.pending_share_changes = fanout_mint.pending_share_changes.saturating_sub(1);
+    }
+}
+
+// All changes in a schedule switch together; a new schedule starts once every change of the
+// previous one has been applied, and an empty schedule can be moved.
+fn schedule_share_switch(fanout: &mut Fanout, effective_at: i64) -> Result<()> {
+    match fanout.share_schedule_at {
+        Some(scheduled_at) if scheduled_at == effective_at => {}
+        Some(_) if fanout.pending_share_changes > 0 => {
+            return Err(HydraError::InvalidShareSchedule.into());
+        }
+        None if fanout.pending_share_changes > 0 => {
+            return Err(HydraError::ShareChangePending.into());
+        }
+        _ => fanout.share_schedule_at = Some(effective_at),
+    }
+    Ok(())
+}
+
+pub fn schedule_share_change(
+    fanout: &mut Fanout,
+    voucher: &mut FanoutMembershipVoucher,
+    shares: u64,
+    effective_at: i64,
+) -> Result<()> {
+    if voucher.expires_at.is_some() {
+        return Err(HydraError::MembershipHasExpiry.into());
+    }
+    cancel_share_change(fanout, voucher)?;
+    schedule_share_switch(fanout, effective_at)?;
+    let delta = (shares as i128)
+        .checked_sub(voucher.shares as i128)
+        .and_then(|d| d.checked_add(fanout.scheduled_share_delta as i128))
+        .or_arith_error()?;
+    if delta > fanout.total_available_shares as i128 {
+        return Err(HydraError::InsufficientShares.into());
+    }
+    fanout.scheduled_share_delta = delta as i64;
+    fanout.pending_share_changes = fanout
+        .pending_share_changes
+        .checked_add(1)
+        .or_arith_error()?;
+    voucher.previous_shares = voucher.shares;
+    voucher.scheduled_shares = Some(shares);
+    voucher.share_epoch = fanout.share_epoch.checked_add(1).or_arith_error()?;
+    Ok(())
+}
+
+// An expiry is a change to no shares, so inflow is split at the switch like for any other change.
+pub fn schedule_expiry(
+    fanout: &mut Fanout,
+    voucher: &mut FanoutMembershipVoucher,
+    expires_at: i64,
+) -> Result<()> {
+    cancel_share_change(fanout, voucher)?;
+    schedule_share_switch(fanout, expires_at)?;
+    fanout.expiring_shares = fanout
+        .expiring_shares
+        .checked_add(voucher.shares)
+        .or_arith_error()?;
+    fanout.pending_share_changes = fanout
+        .pending_share_changes
+        .checked_add(1)
+        .or_arith_error()?;
+    voucher.previous_shares = voucher.shares;
+    voucher.scheduled_shares = Some(0);
+    voucher.share_epoch = fanout.share_epoch.checked_add(1).or_arith_error()?;
+    voucher.expires_at = Some(expires_at);
+    Ok(())
+}
+
+// Drops a change that has not switched yet; one that has must be applied by distributing.
+pub fn cancel_share_change(
+    fanout: &mut Fanout,
+    voucher: &mut FanoutMembershipVoucher,
+) -> Result<()> {
+    let scheduled = match voucher.scheduled_shares {
+        Some(scheduled) => scheduled,
+        None => return Ok(()),
+    };
+    if voucher.share_epoch <= fanout.share_epoch {
+        return Err(HydraError::ShareChangePending.into());
+    }
+    if voucher.expires_at.is_some() {
+        fanout.expiring_shares = fanout
+            .expiring_shares
+            .checked_sub(voucher.shares)
+            .or_arith_error()?;
+        voucher.expires_at = None;
+    } else {
+        fanout.scheduled_share_delta = (fanout.scheduled_share_delta as i128)
+            .checked_sub(scheduled as i128)
+            .and_then(|d| d.checked_add(voucher.shares as i128))
+            .or_arith_error()? as i64;
+    }
+    fanout.pending_share_changes = fanout
+        .pending_share_changes
+        .checked_sub(1)
+        .or_arith_error()?;
+    voucher.scheduled_shares = None;
+    voucher.previous_shares = voucher.shares;
+    // Mint vouchers were settled to the current epoch when the change was scheduled
+    voucher.share_epoch = 0;
+    Ok(())
+}
+
+// Windows are counted from the start of the chain's clock so they line up with fixed
+// reporting periods rather than with the last distribution.
+pub fn current_distribution_window(distribution_window: DistributionWindow) -> Result<u64> {
+    let clock = Clock::get()?;
+    Ok(match distribution_window {
+        DistributionWindow::Epochs { epochs } => clock.epoch / epochs,
+        DistributionWindow::Seconds { seconds } => clock.unix_timestamp as u64 / seconds,
+    })
+}
+
+// Records the current window, returning false if it was already recorded.
+pub fn open_distribution_window(
+    distribution_window: Option<DistributionWindow>,
+    last_window: &mut Option<u64>,
+) -> Result<bool> {
+    let window = match distribution_window {
+        Some(distribution_window) => current_distribution_window(distribution_window)?,
+        None => return Ok(true),
+    };
+    if *last_window == Some(window) {
+        return Ok(false);
+    }
+    *last_window = Some(window);
+    Ok(true)
+}
+
+// Fanout scoped windows take one cranked inflow snapshot per window for the fanout and for each
+// mint, returning false once the window has been used.
+pub fn opens_inflow_window(
+    distribution_window: Option<DistributionWindow>,
+    distribution_window_scope: DistributionWindowScope,
+    last_window: &mut Option<u64>,
+) -> Result<bool> {
+    if distribution_window_scope != DistributionWindowScope::Fanout {
+        return Ok(true);
+    }
+    open_distribution_window(distribution_window, last_window)
+}
+
+// Member scoped windows pay each member once per window, for native and each mint separately.
+pub fn update_member_distribution_window(
+    distribution_window: Option<DistributionWindow>,
+    distribution_window_scope: DistributionWindowScope,
+    last_window: &mut Option<u64>,
+) -> Result<()> {
+    if distribution_window_scope != DistributionWindowScope::Member {
+        return Ok(());
+    }
+    if !open_distribution_window(distribution_window, last_window)? {
+        return Err(HydraError::DistributionWindowUsed.into());
+    }
+    Ok(())
+}
+
+// Distributions that apply a switched share change or an expiry can't be skipped, the
+// voucher has to be settled at the shares it held.
+pub fn must_settle(fanout: &Fanout, voucher: &FanoutMembershipVoucher) -> bool {
+    voucher.scheduled_shares.is_some() && voucher.share_epoch <= fanout.share_epoch
+}
+
+// Only the latest change is kept on the voucher, so shares are unknown for epochs before the
+// one preceding it.
+pub fn shares_at_epoch(voucher: &FanoutMembershipVoucher, epoch: u64) -> Option<u64> {
+    if voucher.share_epoch == 0 {
+        return Some(voucher.shares);
+    }
+    if epoch.checked_add(1)? < voucher.share_epoch {
+        return None;
+    }
+    let switched = epoch >= voucher.share_epoch;
+    Some(match (voucher.scheduled_shares, switched) {
+        (Some(scheduled), true) => scheduled,
+        (Some(_), false) => voucher.shares,
+        (None, true) => voucher.shares,
+        (None, false) => voucher.previous_shares,
+    })
+}
+
+// The epoch a new mint voucher starting from initial_inflow settled at.
+pub fn initial_mint_share_epoch(fanout_mint: &FanoutMint, initial_inflow: u64) -> u64 {
+    if initial_inflow >= fanout_mint.share_switch_inflow {
+        fanout_mint.share_epoch
+    } else {
+        fanout_mint.share_epoch.saturating_sub(1)
+    }
+}
+
+// Splits an inflow range at the switch point into the parts before and after it.
+fn split_at_switch(last_inflow: u64, total_inflow: u64, switch_inflow: u64) -> (u64, u64) {
+    let switch_inflow = switch_inflow.max(last_inflow).min(total_inflow);
+    (switch_inflow - last_inflow, total_inflow - switch_inflow)
+}
+
+pub fn calculate_member_amount(
+    fanout: &mut Fanout,
+    voucher: &mut FanoutMembershipVoucher,
+) -> Result<u64> {
+    let scheduled = match voucher.scheduled_shares {
+        Some(scheduled) if voucher.share_epoch <= fanout.share_epoch => scheduled,
+        _ => {
+            let inflow_diff = calculate_inflow_change(fanout.total_inflow, voucher.last_inflow)?;
+            return calculate_dist_amount(voucher.shares, inflow_diff, fanout.total_shares);
+        }
+    };
+    let (before, after) = split_at_switch(
+        voucher.last_inflow,
+        fanout.total_inflow,
+        fanout.share_switch_inflow,
+    );
+    let amount = calculate_dist_amount(voucher.shares, before, fanout.total_shares)?
+        .checked_add(calculate_dist_amount(
+            scheduled,
+            after,
+            fanout.total_shares,
+        )?)
+        .or_arith_error()?;
+    voucher.shares = scheduled;
+    voucher.scheduled_shares = None;
+    fanout.pending_share_changes = fanout
+        .pending_share_changes
+        .checked_sub(1)
+        .or_arith_error()?;
+    Ok(amount)
+}
+
+// The mint only keeps its latest switch, so a member changed at an earlier switch the mint voucher
+// has not settled across can't be paid; schedules wait for those to settle.
+pub fn calculate_member_mint_amount(
+    fanout: &Fanout,
+    fanout_mint: &FanoutMint,
+    voucher: &FanoutMembershipVoucher,
+    mint_voucher: &mut FanoutMembershipMintVoucher,
+) -> Result<u64> {
+    if let Some(total_shares) = fanout_mint.total_shares {
+        let inflow_diff =
+            calculate_inflow_change(fanout_mint.total_inflow, mint_voucher.last_inflow)?;
+        mint_voucher.share_epoch = fanout_mint.share_epoch;
+        return calculate_dist_amount(mint_voucher.shares, inflow_diff, total_shares);
+    }
+    let shares = shares_at_epoch(voucher, fanout_mint.share_epoch)
+        .ok_or(HydraError::ShareHistoryUnavailable)?;
+    if mint_voucher.share_epoch < voucher.share_epoch && voucher.share_epoch < fanout_mint.share_epoch
+    {
+        return Err(HydraError::ShareHistoryUnavailable.into());
+    }
+    let amount = if mint_voucher.share_epoch >= fanout_mint.share_epoch {
+        let inflow_diff =
+            calculate_inflow_change(fanout_mint.total_inflow, mint_voucher.last_inflow)?;
+        calculate_dist_amount(shares, inflow_diff, fanout.total_shares)?
+    } else {
+        let (before, after) = split_at_switch(
+            mint_voucher.last_inflow,
+            fanout_mint.total_inflow,
+            fanout_mint.share_switch_inflow,
+        );
+        let prior_shares = if before > 0 {
+            shares_at_epoch(voucher, mint_voucher.share_epoch)
+                .ok_or(HydraError::ShareHistoryUnavailable)?
+        } else {
+            0
+        };
+        calculate_dist_amount(prior_shares, before, fanout.total_shares)?
+            .checked_add(calculate_dist_amount(shares, after, fanout.total_shares)?)
+            .or_arith_error()?
+    };
+    mint_voucher.share_epoch = fanout_mint.share_epoch;
+    Ok(amount)
+}
+
+pub fn calculate_reserve_amount(fanout: &Fanout, diff: u64) -> Result<u64> {
+    if fanout.reserve.is_none() || fanout.total_available_shares == 0 {
+        return Ok(0);
+    }
+    let active_shares = fanout
+        .total_shares
+        .checked_sub(fanout.retired_shares)
+        .or_arith_error()?;
+    calculate_dist_amount(fanout.total_available_shares, diff, active_shares)
+}
+
+// For the token model total_shares is a fixed accounting denominator. The observed membership
+// supply is only applied to a holding account's inflow once its pending inflow has been settled
+// at the previously checkpointed supply, so mints and burns only affect later distributions.
+pub fn update_membership_supply(fanout: &mut Fanout, supply: u64) {
+    fanout.membership_supply = supply + fanout.weighted_mint_supply;
+    if fanout.total_shares == 0 {
+        fanout.total_shares = fanout.membership_supply;
+    }
+}
+
+// Additional membership mints count towards the supply with their weight.
+pub fn update_weighted_mint_supply(
+    fanout: &mut Fanout,
+    membership_mint: &mut FanoutMembershipMint,
+    supply: u64,
+) -> Result<()> {
+    let previous = membership_mint
+        .supply
+        .checked_mul(membership_mint.weight)
+        .or_arith_error()?;
+    let current = supply
+        .checked_mul(membership_mint.weight)
+        .or_arith_error()?;
+    fanout.weighted_mint_supply = fanout
+        .weighted_mint_supply
+        .checked_sub(previous)
+        .and_then(|s| s.checked_add(current))
+        .or_arith_error()?;
+    fanout.membership_supply = fanout
+        .membership_supply
+        .checked_sub(previous)
+        .and_then(|s| s.checked_add(current))
+        .or_arith_error()?;
+    membership_mint.supply = supply;
+    if fanout.total_shares == 0 {
+        fanout.total_shares = fanout.membership_supply;
+    }
+    Ok(())
+}
+
+// Shares retired by resigning members are spread pro rata over the remaining shares, as are shares
+// returned to the pool until they are allocated again, unless a reserve collects them.
+fn calculate_retired_inflow(fanout: &Fanout, diff: u64) -> Result<(u64, u64, u64)> {
+    let pooled_shares = if fanout.reserve.is_none() && fanout.shares_released {
+        fanout.total_available_shares
+    } else {
+        0
+    };
+    let inactive_shares = fanout
+        .retired_shares
+        .checked_add(pooled_shares)
+        .or_arith_error()?;
+    if inactive_shares == 0 {
+        return Ok((diff, 0, 0));
+    }
+    let active_shares = fanout
+        .total_shares
+        .checked_sub(inactive_shares)
+        .or_arith_error()?;
+    if active_shares == 0 {
+        return Ok((0, 0, diff));
+    }
+    Ok((
+        calculate_dist_amount(fanout.total_shares, diff, active_shares)?,
+        0,
+        0,
+    ))
+}
+
+// Splits an inflow according to the unstaked policy into
+// (added to the members' inflow, held for the treasury, left for the next snapshot).
+pub fn calculate_inflow_split(
+    fanout: &Fanout,
+    supply_checkpoint: u64,
+    diff: u64,
+) -> Result<(u64, u64, u64)> {
+    let tss = match fanout.total_staked_shares {
+        Some(tss) => tss,
+        None => return calculate_retired_inflow(fanout, diff),
+    };
+    let supply = if supply_checkpoint == 0 {
+        fanout.total_shares
+    } else {
+        supply_checkpoint
+    }
+    .max(tss);
+    if supply == 0 || fanout.total_shares == 0 {
+        return Ok((diff, 0, 0));
+    }
+    let inflow = calculate_dist_amount(fanout.total_shares, diff, supply)?;
+    if tss == 0 {
+        return Ok((inflow, 0, 0));
+    }
+    let unstaked = calculate_dist_amount(supply - tss, diff, supply)?;
+    Ok(match fanout.unstaked_policy {
+        UnstakedPolicy::Redistribute => {
+            (calculate_dist_amount(fanout.total_shares, diff, tss)?, 0, 0)
+        }
+        UnstakedPolicy::Treasury => (inflow, unstaked, 0),
+        UnstakedPolicy::RollForward => (inflow, 0, unstaked),
+    })
+}
+
 pub fn update_fanout_for_remove(
     fanout: &mut Account<Fanout>,
 ) -> Result<()> {
@@ -47,53 +590,90 @@ pub fn update_fanout_for_remove(
     Ok(())
 }
 
+// Mints holding reserve inflow are counted on the fanout, so the reserve can't be changed while any
+// of them is unclaimed.
+pub fn calculate_unallocated_mint_amount(fanout_for_mint: &FanoutMint, diff: u64) -> Result<u64> {
+    match fanout_for_mint.total_shares {
+        Some(total_shares) if fanout_for_mint.total_available_shares > 0 => {
+            calculate_dist_amount(fanout_for_mint.total_available_shares, diff, total_shares)
+        }
+        _ => Ok(0),
+    }
+}
+
+pub fn add_mint_reserve(
+    fanout: &mut Fanout,
+    fanout_for_mint: &mut FanoutMint,
+    amount: u64,
+) -> Result<()> {
+    if amount > 0 && fanout_for_mint.reserve_unclaimed == 0 {
+        fanout.mints_with_reserve = fanout.mints_with_reserve.checked_add(1).or_arith_error()?;
+    }
+    fanout_for_mint.reserve_unclaimed = fanout_for_mint
+        .reserve_unclaimed
+        .checked_add(amount)
+        .or_arith_error()?;
+    Ok(())
+}
+
 pub fn update_inflow_for_mint(
-    fanout: &mut Account<Fanout>,
+    fanout: &mut Fanout,
     fanout_for_mint: &mut FanoutMint,
     current_snapshot: u64,
 ) -> Result<()> {
     let diff = current_snapshot
         .checked_sub(fanout_for_mint.last_snapshot_amount)
         .or_arith_error()?;
+    // A mint with its own share table is split only by that table
+    let (inflow, mut treasury, rolled) = if fanout_for_mint.total_shares.is_some() {
+        (diff, 0, 0)
+    } else {
+        calculate_inflow_split(fanout, fanout_for_mint.supply_checkpoint, diff)?
+    };
+    let mut reserve = if fanout_for_mint.total_shares.is_some() {
+        0
+    } else {
+        calculate_reserve_amount(fanout, diff)?
+    };
+    // Inflow of shares unallocated in a share table goes to the reserve, or else the treasury
+    let unallocated = calculate_unallocated_mint_amount(fanout_for_mint, diff)?;
+    if fanout.reserve.is_some() {
+        reserve = reserve.checked_add(unallocated).or_arith_error()?;
+    } else {
+        treasury = treasury.checked_add(unallocated).or_arith_error()?;
+    }
+    fanout_for_mint.supply_checkpoint = fanout.membership_supply;
     fanout_for_mint.total_inflow = fanout_for_mint
         .total_inflow
-        .checked_add(diff)
+        .checked_add(inflow)
         .or_arith_error()?;
-    if fanout.total_staked_shares.is_some() && fanout.total_staked_shares.unwrap() > 0 {
-        let tss = fanout.total_staked_shares.unwrap();
-        let shares_diff = (fanout.total_shares as u64)
-            .checked_sub(tss)
-            .or_arith_error()?;
-        let unstaked_correction = (diff as u128)
-            .checked_mul(shares_diff as u128)
-            .or_arith_error()?
-            .checked_div(tss as u128)
-            .or_arith_error()? as u64;
-        fanout_for_mint.total_inflow += unstaked_correction;
-    }
-    fanout_for_mint.last_snapshot_amount = current_snapshot;
-    Ok(())
+    add_mint_reserve(fanout, fanout_for_mint, reserve)?;
+    fanout_for_mint.treasury_unclaimed = fanout_for_mint
+        .treasury_unclaimed
+        .checked_add(treasury)
+        .or_arith_error()?;
+    fanout_for_mint.last_snapshot_amount = current_snapshot.checked_sub(rolled).or_arith_error()?;
+    update_share_schedule_for_mint(fanout, fanout_for_mint)
 }
 
 pub fn update_inflow(fanout: &mut Fanout, current_snapshot: u64) -> Result<()> {
     let diff = current_snapshot
         .checked_sub(fanout.last_snapshot_amount)
         .or_arith_error()?;
-    fanout.total_inflow = fanout.total_inflow.checked_add(diff).or_arith_error()?;
-    if fanout.total_staked_shares.is_some() && fanout.total_staked_shares.unwrap() > 0 {
-        let tss = fanout.total_staked_shares.unwrap();
-        let shares_diff = (fanout.total_shares as u64)
-            .checked_sub(tss)
-            .or_arith_error()?;
-        let unstaked_correction = (diff as u128)
-            .checked_mul(shares_diff as u128)
-            .or_arith_error()?
-            .checked_div(tss as u128)
-            .or_arith_error()? as u64;
-        fanout.total_inflow += unstaked_correction;
-    }
-    fanout.last_snapshot_amount = current_snapshot;
-    Ok(())
+    let (inflow, treasury, rolled) =
+        calculate_inflow_split(fanout, fanout.supply_checkpoint, diff)?;
+    fanout.supply_checkpoint = fanout.membership_supply;
+    fanout.total_inflow = fanout.total_inflow.checked_add(inflow).or_arith_error()?;
+    fanout.reserve_unclaimed = fanout
+        .reserve_unclaimed
+        .checked_add(calculate_reserve_amount(fanout, diff)?)
+        .or_arith_error()?;
+    fanout.treasury_unclaimed = fanout
+        .treasury_unclaimed
+        .checked_add(treasury)
+        .or_arith_error()?;
+    fanout.last_snapshot_amount = current_snapshot.checked_sub(rolled).or_arith_error()?;
+    update_share_schedule(fanout)
 }
 
 pub fn update_snapshot(
@@ -132,3 +712,465 @@ pub fn current_lamports(
         .checked_sub(subtract_size)
         .ok_or(HydraError::NumericalOverflow.into())
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use anchor_lang::solana_program::entrypoint::SUCCESS;
+    use anchor_lang::solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+    use std::sync::{Mutex, MutexGuard};
+
+    // The sysvar stubs are process wide, so tests reading the clock or rent hold this lock.
+    static CLOCK: Mutex<()> = Mutex::new(());
+
+    struct ClockStubs {
+        unix_timestamp: i64,
+        epoch: u64,
+    }
+
+    impl SyscallStubs for ClockStubs {
+        fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+            unsafe {
+                *(var_addr as *mut Clock) = Clock {
+                    unix_timestamp: self.unix_timestamp,
+                    epoch: self.epoch,
+                    ..Clock::default()
+                };
+            }
+            SUCCESS
+        }
+
+        fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+            unsafe {
+                *(var_addr as *mut Rent) = Rent::default();
+            }
+            SUCCESS
+        }
+    }
+
+    pub(crate) fn set_clock(unix_timestamp: i64, epoch: u64) -> MutexGuard<'static, ()> {
+        let guard = CLOCK.lock().unwrap_or_else(|e| e.into_inner());
+        set_syscall_stubs(Box::new(ClockStubs {
+            unix_timestamp,
+            epoch,
+        }));
+        guard
+    }
+
+    fn fanout(total_shares: u64) -> Fanout {
+        Fanout {
+            total_shares,
+            ..Default::default()
+        }
+    }
+
+    fn voucher(shares: u64) -> FanoutMembershipVoucher {
+        FanoutMembershipVoucher {
+            shares,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_expiry_switches_shares_to_zero() {
+        let clock = set_clock(100, 0);
+        let mut fanout = fanout(100);
+        let mut member = voucher(50);
+        let mut other = voucher(50);
+        schedule_expiry(&mut fanout, &mut member, 150).unwrap();
+        assert_eq!(fanout.expiring_shares, 50);
+        assert_eq!(member.expires_at, Some(150));
+        update_inflow(&mut fanout, 400).unwrap();
+        assert_eq!(fanout.share_epoch, 0);
+        drop(clock);
+        let _clock = set_clock(200, 0);
+        // The snapshot releasing the member switches at the inflow recorded by the expiry
+        update_inflow(&mut fanout, 400).unwrap();
+        assert_eq!(fanout.share_epoch, 1);
+        assert_eq!(fanout.expiring_shares, 0);
+        assert_eq!(fanout.total_available_shares, 50);
+        assert!(fanout.shares_released);
+        update_inflow(&mut fanout, 1000).unwrap();
+        assert_eq!(calculate_member_amount(&mut fanout, &mut member).unwrap(), 200);
+        assert_eq!(member.shares, 0);
+        // Released shares are spread over the remaining members until allocated again
+        assert_eq!(calculate_member_amount(&mut fanout, &mut other).unwrap(), 800);
+        assert_eq!(fanout.pending_share_changes, 0);
+    }
+
+    #[test]
+    fn test_mint_inflow_splits_at_expiry() {
+        let clock = set_clock(100, 0);
+        let mut fanout = Fanout {
+            resignation_policy: ResignationPolicy::Redistribute,
+            ..fanout(100)
+        };
+        let mut fanout_mint = FanoutMint::default();
+        let mut member = voucher(25);
+        schedule_expiry(&mut fanout, &mut member, 150).unwrap();
+        update_inflow_for_mint(&mut fanout, &mut fanout_mint, 400).unwrap();
+        drop(clock);
+        let _clock = set_clock(200, 0);
+        update_inflow_for_mint(&mut fanout, &mut fanout_mint, 400).unwrap();
+        assert_eq!(fanout.retired_shares, 25);
+        assert_eq!(fanout_mint.share_epoch, 1);
+        update_inflow_for_mint(&mut fanout, &mut fanout_mint, 1200).unwrap();
+        let mut mint_voucher = FanoutMembershipMintVoucher::default();
+        let amount =
+            calculate_member_mint_amount(&fanout, &fanout_mint, &member, &mut mint_voucher)
+                .unwrap();
+        assert_eq!(amount, 100);
+    }
+
+    #[test]
+    fn test_cleared_expiry() {
+        let _clock = set_clock(100, 0);
+        let mut fanout = fanout(100);
+        let mut member = voucher(50);
+        schedule_expiry(&mut fanout, &mut member, 150).unwrap();
+        let result = schedule_share_change(&mut fanout, &mut member, 30, 150);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            Error::from(HydraError::MembershipHasExpiry).to_string()
+        );
+        cancel_share_change(&mut fanout, &mut member).unwrap();
+        assert_eq!(fanout.expiring_shares, 0);
+        assert_eq!(fanout.pending_share_changes, 0);
+        assert_eq!(member.expires_at, None);
+        assert_eq!(member.scheduled_shares, None);
+        // The emptied schedule can be moved
+        schedule_share_change(&mut fanout, &mut member, 30, 300).unwrap();
+        assert_eq!(fanout.share_schedule_at, Some(300));
+    }
+
+    // Member changes 50 -> 30 shares at epoch 1, which the mint adopts after 100 of inflow.
+    fn scheduled_change() -> (Fanout, FanoutMint, FanoutMembershipVoucher) {
+        let mut fanout = fanout(100);
+        let mut member = voucher(50);
+        schedule_share_change(&mut fanout, &mut member, 30, 100).unwrap();
+        let mut fanout_mint = FanoutMint::default();
+        update_inflow_for_mint(&mut fanout, &mut fanout_mint, 100).unwrap();
+        (fanout, fanout_mint, member)
+    }
+
+    #[test]
+    fn test_mint_settles_across_share_change() {
+        let _clock = set_clock(150, 0);
+        let (mut fanout, mut fanout_mint, member) = scheduled_change();
+        assert_eq!(fanout.share_epoch, 1);
+        assert_eq!(fanout_mint.share_epoch, 1);
+        assert_eq!(fanout_mint.pending_share_changes, 1);
+        update_inflow_for_mint(&mut fanout, &mut fanout_mint, 300).unwrap();
+        let mut mint_voucher = FanoutMembershipMintVoucher::default();
+        let amount =
+            calculate_member_mint_amount(&fanout, &fanout_mint, &member, &mut mint_voucher)
+                .unwrap();
+        // 100 at the old 50 shares and 200 at the new 30
+        assert_eq!(amount, 50 + 60);
+        settle_mint_share_change(&mut fanout_mint, &member, 0, false);
+        assert_eq!(fanout_mint.pending_share_changes, 0);
+        assert_eq!(mint_voucher.share_epoch, 1);
+    }
+
+    #[test]
+    fn test_mint_settling_two_epochs_after_share_change() {
+        let _clock = set_clock(250, 0);
+        let (mut fanout, mut fanout_mint, member) = scheduled_change();
+        let unchanged = voucher(20);
+        // A later schedule switches at epoch 2 after 300 of mint inflow
+        fanout.share_schedule_at = Some(200);
+        update_inflow_for_mint(&mut fanout, &mut fanout_mint, 300).unwrap();
+        assert_eq!(fanout_mint.share_epoch, 2);
+        let mut mint_voucher = FanoutMembershipMintVoucher::default();
+        let result =
+            calculate_member_mint_amount(&fanout, &fanout_mint, &member, &mut mint_voucher);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            Error::from(HydraError::ShareHistoryUnavailable).to_string()
+        );
+        // Members whose shares did not change in between are paid as usual
+        let mut mint_voucher = FanoutMembershipMintVoucher::default();
+        let amount =
+            calculate_member_mint_amount(&fanout, &fanout_mint, &unchanged, &mut mint_voucher)
+                .unwrap();
+        assert_eq!(amount, 60);
+    }
+
+    #[test]
+    fn test_initial_mint_share_epoch() {
+        let fanout_mint = FanoutMint {
+            share_epoch: 3,
+            share_switch_inflow: 100,
+            ..Default::default()
+        };
+        assert_eq!(initial_mint_share_epoch(&fanout_mint, 100), 3);
+        assert_eq!(initial_mint_share_epoch(&fanout_mint, 0), 2);
+    }
+
+    #[test]
+    fn test_unallocated_share_table_inflow() {
+        let mut fanout = fanout(100);
+        let mut fanout_mint = FanoutMint {
+            total_shares: Some(10),
+            total_available_shares: 4,
+            ..Default::default()
+        };
+        update_inflow_for_mint(&mut fanout, &mut fanout_mint, 500).unwrap();
+        assert_eq!(fanout_mint.total_inflow, 500);
+        assert_eq!(fanout_mint.treasury_unclaimed, 200);
+        assert_eq!(fanout_mint.reserve_unclaimed, 0);
+        fanout.reserve = Some(Pubkey::new_unique());
+        update_inflow_for_mint(&mut fanout, &mut fanout_mint, 1000).unwrap();
+        assert_eq!(fanout_mint.reserve_unclaimed, 200);
+        assert_eq!(fanout.mints_with_reserve, 1);
+    }
+
+    #[test]
+    fn test_fanout_window_snapshots_once() {
+        let window = Some(DistributionWindow::Seconds { seconds: 100 });
+        let mut last_window = None;
+        assert!(
+            opens_inflow_window(None, DistributionWindowScope::Fanout, &mut last_window).unwrap()
+        );
+        let clock = set_clock(250, 0);
+        assert!(
+            opens_inflow_window(window, DistributionWindowScope::Fanout, &mut last_window).unwrap()
+        );
+        assert!(
+            !opens_inflow_window(window, DistributionWindowScope::Fanout, &mut last_window)
+                .unwrap()
+        );
+        assert!(
+            opens_inflow_window(window, DistributionWindowScope::Member, &mut last_window).unwrap()
+        );
+        drop(clock);
+        let _clock = set_clock(300, 0);
+        assert!(
+            opens_inflow_window(window, DistributionWindowScope::Fanout, &mut last_window).unwrap()
+        );
+        assert_eq!(last_window, Some(3));
+    }
+
+    #[test]
+    fn test_member_window_pays_once() {
+        let _clock = set_clock(0, 9);
+        let window = Some(DistributionWindow::Epochs { epochs: 4 });
+        let mut last_window = None;
+        update_member_distribution_window(
+            window,
+            DistributionWindowScope::Member,
+            &mut last_window,
+        )
+        .unwrap();
+        assert_eq!(last_window, Some(2));
+        assert!(update_member_distribution_window(
+            window,
+            DistributionWindowScope::Member,
+            &mut last_window
+        )
+        .is_err());
+        // Each mint voucher keeps its own window
+        let mut mint_window = None;
+        update_member_distribution_window(
+            window,
+            DistributionWindowScope::Member,
+            &mut mint_window,
+        )
+        .unwrap();
+        update_member_distribution_window(
+            window,
+            DistributionWindowScope::Fanout,
+            &mut last_window,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_calculate_fee() {
+        assert_eq!(calculate_fee(10_000, 250).unwrap(), 250);
+        assert_eq!(calculate_fee(399, 250).unwrap(), 9);
+        assert_eq!(calculate_fee(u64::MAX, 10000).unwrap(), u64::MAX);
+        assert_eq!(calculate_fee(1_000, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_crank_reward_is_capped() {
+        let fixed = Some(CrankReward::Fixed { lamports: 5_000 });
+        assert_eq!(calculate_crank_reward(fixed, 1_000_000).unwrap(), 5_000);
+        // A fixed reward never takes more than a tenth of a small payout
+        assert_eq!(calculate_crank_reward(fixed, 20_000).unwrap(), 2_000);
+        let basis_points = Some(CrankReward::BasisPoints {
+            basis_points: 100,
+            max_lamports: 500,
+        });
+        assert_eq!(calculate_crank_reward(basis_points, 10_000).unwrap(), 100);
+        assert_eq!(
+            calculate_crank_reward(basis_points, 1_000_000).unwrap(),
+            500
+        );
+        assert_eq!(calculate_crank_reward(None, 1_000_000).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_mint_crank_reward() {
+        let fixed = Some(CrankReward::Fixed { lamports: 5_000 });
+        assert_eq!(calculate_mint_crank_reward(fixed, 1_000_000).unwrap(), 0);
+        let basis_points = Some(CrankReward::BasisPoints {
+            basis_points: 100,
+            max_lamports: 500,
+        });
+        // The lamport maximum does not apply to token amounts
+        assert_eq!(
+            calculate_mint_crank_reward(basis_points, 1_000_000).unwrap(),
+            10_000
+        );
+        let basis_points = Some(CrankReward::BasisPoints {
+            basis_points: 5_000,
+            max_lamports: 0,
+        });
+        assert_eq!(
+            calculate_mint_crank_reward(basis_points, 1_000).unwrap(),
+            100
+        );
+    }
+
+    #[test]
+    fn test_reserve_holds_unallocated_inflow() {
+        let mut fanout = Fanout {
+            total_available_shares: 25,
+            reserve: Some(Pubkey::new_unique()),
+            ..fanout(100)
+        };
+        update_inflow(&mut fanout, 400).unwrap();
+        assert_eq!(fanout.total_inflow, 400);
+        assert_eq!(fanout.reserve_unclaimed, 100);
+        // Members joining later start from the current inflow
+        let mut member = voucher(25);
+        update_voucher_for_add(&fanout, &mut member);
+        assert_eq!(member.last_inflow, 400);
+        let mut fanout_mint = FanoutMint::default();
+        update_inflow_for_mint(&mut fanout, &mut fanout_mint, 800).unwrap();
+        assert_eq!(fanout_mint.reserve_unclaimed, 200);
+        assert_eq!(fanout.mints_with_reserve, 1);
+    }
+
+    #[test]
+    fn test_unstaked_inflow_split() {
+        let mut fanout = Fanout {
+            total_staked_shares: Some(60),
+            unstaked_policy: UnstakedPolicy::Treasury,
+            ..fanout(100)
+        };
+        assert_eq!(
+            calculate_inflow_split(&fanout, 100, 1000).unwrap(),
+            (1000, 400, 0)
+        );
+        update_inflow(&mut fanout, 1000).unwrap();
+        assert_eq!(fanout.treasury_unclaimed, 400);
+        assert_eq!(fanout.last_snapshot_amount, 1000);
+        fanout.unstaked_policy = UnstakedPolicy::RollForward;
+        assert_eq!(
+            calculate_inflow_split(&fanout, 100, 1000).unwrap(),
+            (1000, 0, 400)
+        );
+        fanout.unstaked_policy = UnstakedPolicy::Redistribute;
+        assert_eq!(
+            calculate_inflow_split(&fanout, 100, 1000).unwrap(),
+            (1666, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_supply_change_applies_after_checkpoint() {
+        let mut fanout = Fanout {
+            total_staked_shares: Some(50),
+            unstaked_policy: UnstakedPolicy::Treasury,
+            ..fanout(0)
+        };
+        update_membership_supply(&mut fanout, 100);
+        assert_eq!(fanout.total_shares, 100);
+        update_inflow(&mut fanout, 1000).unwrap();
+        let mut fanout_mint = FanoutMint::default();
+        update_inflow_for_mint(&mut fanout, &mut fanout_mint, 0).unwrap();
+        assert_eq!(fanout.supply_checkpoint, 100);
+        assert_eq!(fanout_mint.supply_checkpoint, 100);
+        // Minting doubles the supply, but pending inflow still settles at the old supply
+        update_membership_supply(&mut fanout, 200);
+        assert_eq!(fanout.total_shares, 100);
+        update_inflow(&mut fanout, 2000).unwrap();
+        assert_eq!(fanout.total_inflow, 2000);
+        assert_eq!(fanout.treasury_unclaimed, 1000);
+        assert_eq!(fanout.supply_checkpoint, 200);
+        update_inflow_for_mint(&mut fanout, &mut fanout_mint, 1000).unwrap();
+        assert_eq!(fanout_mint.total_inflow, 1000);
+        assert_eq!(fanout_mint.treasury_unclaimed, 500);
+        assert_eq!(fanout_mint.supply_checkpoint, 200);
+        // Later inflow is split over the new supply
+        update_inflow(&mut fanout, 3000).unwrap();
+        assert_eq!(fanout.total_inflow, 2500);
+        assert_eq!(fanout.treasury_unclaimed, 1750);
+    }
+
+    #[test]
+    fn test_weighted_mint_supply() {
+        let mut fanout = fanout(0);
+        update_membership_supply(&mut fanout, 100);
+        let mut membership_mint = FanoutMembershipMint {
+            weight: 3,
+            ..Default::default()
+        };
+        update_weighted_mint_supply(&mut fanout, &mut membership_mint, 10).unwrap();
+        assert_eq!(fanout.weighted_mint_supply, 30);
+        assert_eq!(fanout.membership_supply, 130);
+        assert_eq!(fanout.total_shares, 100);
+        // A burn replaces the mint's previous weighted supply
+        update_weighted_mint_supply(&mut fanout, &mut membership_mint, 4).unwrap();
+        assert_eq!(membership_mint.supply, 4);
+        assert_eq!(fanout.weighted_mint_supply, 12);
+        assert_eq!(fanout.membership_supply, 112);
+        // The primary mint's supply is counted alongside it
+        update_membership_supply(&mut fanout, 50);
+        assert_eq!(fanout.membership_supply, 62);
+    }
+
+    #[test]
+    fn test_released_shares_inflow() {
+        let mut pooled = fanout(100);
+        release_shares(&mut pooled, 20).unwrap();
+        assert!(pooled.shares_released);
+        assert_eq!(pooled.total_available_shares, 20);
+        update_inflow(&mut pooled, 800).unwrap();
+        // The remaining 80 shares earn all of the inflow
+        assert_eq!(
+            calculate_dist_amount(40, pooled.total_inflow, pooled.total_shares).unwrap(),
+            400
+        );
+        let mut retiring = Fanout {
+            resignation_policy: ResignationPolicy::Redistribute,
+            ..fanout(100)
+        };
+        release_shares(&mut retiring, 20).unwrap();
+        assert_eq!(retiring.retired_shares, 20);
+        assert_eq!(retiring.total_available_shares, 0);
+        update_inflow(&mut retiring, 800).unwrap();
+        assert_eq!(retiring.total_inflow, 1000);
+    }
+
+    #[test]
+    fn test_threshold_exempt_settles() {
+        let _clock = set_clock(1_000, 0);
+        let mut fanout = fanout(100);
+        let mut member = voucher(50);
+        let mut expiring = voucher(20);
+        assert!(!must_settle(&fanout, &member));
+        // A switched share change has to be settled below the threshold
+        schedule_share_change(&mut fanout, &mut member, 30, 500).unwrap();
+        schedule_expiry(&mut fanout, &mut expiring, 500).unwrap();
+        assert!(!must_settle(&fanout, &member));
+        assert!(!must_settle(&fanout, &expiring));
+        update_inflow(&mut fanout, 0).unwrap();
+        assert!(must_settle(&fanout, &member));
+        // So does an expiry
+        assert!(must_settle(&fanout, &expiring));
+    }
+}
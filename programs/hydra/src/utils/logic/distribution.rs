@@ -1,4 +1,8 @@
-use crate::state::{Fanout, FanoutMembershipVoucher, HOLDING_ACCOUNT_SIZE};
+use crate::error::OrArithError;
+use crate::events::Distributed;
+use crate::state::{
+    Fanout, FanoutMembershipMintVoucher, FanoutMembershipVoucher, FanoutMint, HOLDING_ACCOUNT_SIZE,
+};
 use crate::utils::logic::calculation::*;
 use crate::utils::logic::transfer::{transfer_from_mint_holding, transfer_native};
 use crate::utils::parse_fanout_mint;
@@ -7,34 +11,115 @@ use crate::utils::*;
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token};
 
+// Payouts go to the member unless their voucher names a payout destination, which is then
+// passed in the remaining accounts.
+pub fn payout_recipient<'info>(
+    membership_voucher: &FanoutMembershipVoucher,
+    member: UncheckedAccount<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<UncheckedAccount<'info>> {
+    match membership_voucher.payout_destination {
+        None => Ok(member),
+        Some(destination) => find_remaining_account(remaining_accounts, &destination)
+            .map(UncheckedAccount::try_from)
+            .ok_or_else(|| HydraError::InvalidPayoutDestination.into()),
+    }
+}
+
+// Distributions paid by the wallet holding the membership or by the authority are not cranks. For
+// NFT members that is the holder of the membership NFT rather than the membership key.
 pub fn distribute_native<'info>(
     holding_account: &mut UncheckedAccount<'info>,
     fanout: &mut Account<'info, Fanout>,
     membership_voucher: &mut Account<'info, FanoutMembershipVoucher>,
     member: UncheckedAccount<'info>,
+    payer: AccountInfo<'info>,
+    holder: &Pubkey,
     rent: Sysvar<'info, anchor_lang::prelude::Rent>,
+    remaining_accounts: &[AccountInfo<'info>],
 ) -> Result<()> {
-    let total_shares = fanout.total_shares as u64;
     if holding_account.key() != fanout.account_key {
         return Err(HydraError::InvalidHoldingAccount.into());
     }
     let current_snapshot = holding_account.lamports();
     let current_snapshot_less_min =
         current_lamports(&rent, HOLDING_ACCOUNT_SIZE, current_snapshot)?;
+    let cranked = payer.key() != *holder && payer.key() != fanout.authority;
+    if cranked
+        && !opens_inflow_window(
+            fanout.distribution_window,
+            fanout.distribution_window_scope,
+            &mut fanout.last_distribution_window,
+        )?
+    {
+        return Err(HydraError::DistributionWindowUsed.into());
+    }
     update_inflow(fanout, current_snapshot_less_min)?;
-    let inflow_diff = calculate_inflow_change(fanout.total_inflow, membership_voucher.last_inflow)?;
-    let shares = membership_voucher.shares as u64;
-    let dif_dist = calculate_dist_amount(shares, inflow_diff, total_shares)?;
+    let settles_shares = must_settle(fanout, membership_voucher);
+    let dif_dist = calculate_member_amount(fanout, membership_voucher)?;
+    // Amounts below the threshold stay owed on the voucher, unless the member or the authority
+    // pays for the distribution
+    if dif_dist < fanout.min_distribution && !settles_shares && cranked {
+        return Ok(());
+    }
+    if cranked && !settles_shares {
+        update_member_distribution_window(
+            fanout.distribution_window,
+            fanout.distribution_window_scope,
+            &mut membership_voucher.last_distribution_window,
+        )?;
+    }
     update_snapshot(fanout, membership_voucher, dif_dist)?;
+    let fee = calculate_fee(dif_dist, fanout.fee_basis_points)?;
+    let after_fee = dif_dist.checked_sub(fee).or_arith_error()?;
+    let crank_reward = calculate_crank_reward(fanout.crank_reward, after_fee)?;
+    let member_dist = after_fee.checked_sub(crank_reward).or_arith_error()?;
+    // The fee recipient is only required in the remaining accounts when a fee is taken
+    let fee_recipient = if fee > 0 {
+        let fee_recipient_key = fanout
+            .fee_recipient
+            .ok_or(HydraError::InvalidFeeRecipient)?;
+        find_remaining_account(remaining_accounts, &fee_recipient_key)
+            .ok_or(HydraError::InvalidFeeRecipient)?
+    } else {
+        payer.clone()
+    };
+    fanout.total_fees = fanout.total_fees.checked_add(fee).or_arith_error()?;
+    fanout.total_crank_rewards = fanout
+        .total_crank_rewards
+        .checked_add(crank_reward)
+        .or_arith_error()?;
     membership_voucher.total_inflow = membership_voucher
         .total_inflow
-        .checked_add(dif_dist)
+        .checked_add(member_dist)
         .ok_or(HydraError::NumericalOverflow)?;
+    emit!(Distributed {
+        fanout: fanout.key(),
+        membership_key: membership_voucher.membership_key,
+        member: member.key(),
+        mint: None,
+        amount: member_dist,
+        fee,
+        crank_reward,
+        total_inflow: fanout.total_inflow,
+    });
     transfer_native(
         holding_account.to_account_info(),
         member.to_account_info(),
         current_snapshot,
-        dif_dist,
+        member_dist,
+    )?;
+    transfer_native(
+        holding_account.to_account_info(),
+        fee_recipient,
+        holding_account.lamports(),
+        fee,
+    )?;
+    transfer_native(
+        holding_account.to_account_info(),
+        payer,
+        holding_account.lamports(),
+        crank_reward,
     )
 }
 
@@ -52,13 +137,17 @@ pub fn distribute_mint<'info>(
     payer: AccountInfo<'info>,
     member: UncheckedAccount<'info>,
     membership_key: &Pubkey,
+    holder: &Pubkey,
+    remaining_accounts: &[AccountInfo<'info>],
 ) -> Result<()> {
     msg!("Distribute For Mint");
     let mint = &fanout_mint;
     let fanout_for_mint_membership_voucher_unchecked = fanout_for_mint_membership_voucher;
     let fanout_mint_member_token_account_info = fanout_mint_member_token_account.to_account_info();
     let fanout_for_mint = fanout_for_mint;
-    let total_shares = fanout.total_shares as u64;
+    if fanout.unwrap_native_mint && mint.key() == spl_token::native_mint::id() {
+        return Err(HydraError::NativeMintUnwrapped.into());
+    }
     assert_owned_by(&fanout_for_mint, &crate::ID)?;
     assert_owned_by(&fanout_mint_member_token_account_info, &Token::id())?;
     assert_owned_by(holding_account, &anchor_spl::token::Token::id())?;
@@ -76,6 +165,51 @@ pub fn distribute_mint<'info>(
     if fanout_for_mint_object.mint != mint.to_account_info().key() {
         return Err(HydraError::MintDoesNotMatch.into());
     }
+    // Members joining late have their mint vouchers created as they join, so a missing one starts
+    // from the mint's first inflow
+    let initial_inflow = 0;
+    let initial_epoch = initial_mint_share_epoch(fanout_for_mint_object, initial_inflow);
+    let holding_account_ata = parse_token_account(holding_account, &fanout.key())?;
+    parse_token_account(&fanout_mint_member_token_account_info, &member.key())?;
+
+    let current_snapshot = holding_account_ata.amount;
+    let cranked = payer.key() != *holder && payer.key() != fanout.authority;
+    if cranked
+        && !opens_inflow_window(
+            fanout.distribution_window,
+            fanout.distribution_window_scope,
+            &mut fanout_for_mint_object.last_distribution_window,
+        )?
+    {
+        return Err(HydraError::DistributionWindowUsed.into());
+    }
+    update_inflow_for_mint(fanout, fanout_for_mint_object, current_snapshot)?;
+    let min_distribution = if cranked {
+        fanout_for_mint_object.min_distribution
+    } else {
+        0
+    };
+    // No mint voucher is created for an amount below the threshold
+    let creates_voucher = fanout_for_mint_membership_voucher_unchecked.data_is_empty();
+    if creates_voucher {
+        let new_voucher = &mut FanoutMembershipMintVoucher {
+            last_inflow: initial_inflow,
+            share_epoch: initial_epoch,
+            ..Default::default()
+        };
+        let amount = calculate_member_mint_amount(
+            fanout,
+            fanout_for_mint_object,
+            membership_voucher,
+            new_voucher,
+        )?;
+        let crosses_change = membership_voucher.share_epoch > 0
+            && membership_voucher.share_epoch == fanout_for_mint_object.share_epoch;
+        if amount < min_distribution && !crosses_change {
+            let mut fanout_for_mint_data: &mut [u8] = &mut fanout_for_mint.try_borrow_mut_data()?;
+            return fanout_for_mint_object.try_serialize(&mut fanout_for_mint_data);
+        }
+    }
     let fanout_for_mint_membership_voucher = &mut parse_mint_membership_voucher(
         fanout_for_mint_membership_voucher_unchecked,
         &rent,
@@ -85,24 +219,83 @@ pub fn distribute_mint<'info>(
         &fanout_for_mint.key(),
         &mint.key(),
         &fanout.key(),
+        initial_inflow,
     )?;
-    let holding_account_ata = parse_token_account(holding_account, &fanout.key())?;
-    parse_token_account(&fanout_mint_member_token_account_info, &member.key())?;
-
-    let current_snapshot = holding_account_ata.amount;
-    update_inflow_for_mint(fanout, fanout_for_mint_object, current_snapshot)?;
-    let inflow_diff = calculate_inflow_change(
-        fanout_for_mint_object.total_inflow,
-        fanout_for_mint_membership_voucher.last_inflow,
+    if creates_voucher {
+        fanout_for_mint_membership_voucher.share_epoch = initial_epoch;
+    }
+    let settled_epoch = fanout_for_mint_membership_voucher.share_epoch;
+    let settles_epoch = settled_epoch < fanout_for_mint_object.share_epoch;
+    let dif_dist = calculate_member_mint_amount(
+        fanout,
+        fanout_for_mint_object,
+        membership_voucher,
+        fanout_for_mint_membership_voucher,
     )?;
-    let shares = membership_voucher.shares as u64;
-    let dif_dist = calculate_dist_amount(shares, inflow_diff, total_shares)?;
+    settle_mint_share_change(
+        fanout_for_mint_object,
+        membership_voucher,
+        settled_epoch,
+        creates_voucher,
+    );
+    if dif_dist < min_distribution && !settles_epoch {
+        let mut fanout_for_mint_data: &mut [u8] = &mut fanout_for_mint.try_borrow_mut_data()?;
+        return fanout_for_mint_object.try_serialize(&mut fanout_for_mint_data);
+    }
+    if cranked && !settles_epoch {
+        update_member_distribution_window(
+            fanout.distribution_window,
+            fanout.distribution_window_scope,
+            &mut fanout_for_mint_membership_voucher.last_distribution_window,
+        )?;
+    }
     update_snapshot_for_mint(
         fanout_for_mint_object,
         fanout_for_mint_membership_voucher,
         dif_dist,
     )?;
+    let fee = calculate_fee(dif_dist, fanout.fee_basis_points)?;
+    let after_fee = dif_dist.checked_sub(fee).or_arith_error()?;
+    // Cranks are rewarded into their token account for the mint, passed in the remaining accounts
+    let crank_reward_account = if payer.key() == *holder {
+        None
+    } else {
+        find_remaining_token_account(remaining_accounts, &payer.key(), &mint.key())
+    };
+    let crank_reward = match crank_reward_account {
+        Some(_) => calculate_mint_crank_reward(fanout.crank_reward, after_fee)?,
+        None => 0,
+    };
+    let member_dist = after_fee.checked_sub(crank_reward).or_arith_error()?;
+    // For mints the fee recipient's token account is passed in the remaining accounts
+    let fee_recipient = if fee > 0 {
+        let fee_recipient_key = fanout
+            .fee_recipient
+            .ok_or(HydraError::InvalidFeeRecipient)?;
+        find_remaining_token_account(remaining_accounts, &fee_recipient_key, &mint.key())
+            .ok_or(HydraError::InvalidFeeRecipient)?
+    } else {
+        holding_account.to_account_info()
+    };
+    fanout_for_mint_membership_voucher.total_inflow = fanout_for_mint_membership_voucher
+        .total_inflow
+        .checked_add(member_dist)
+        .or_arith_error()?;
+    fanout_for_mint_object.total_fees = fanout_for_mint_object
+        .total_fees
+        .checked_add(fee)
+        .or_arith_error()?;
 
+    emit!(Distributed {
+        fanout: fanout.key(),
+        membership_key: *membership_key,
+        member: member.key(),
+        mint: Some(mint.key()),
+        amount: member_dist,
+        fee,
+        crank_reward,
+        total_inflow: fanout_for_mint_object.total_inflow,
+    });
     let mut fanout_for_mint_membership_voucher_data: &mut [u8] =
         &mut fanout_for_mint_membership_voucher_unchecked.try_borrow_mut_data()?;
     let mut fanout_for_mint_data: &mut [u8] = &mut fanout_for_mint.try_borrow_mut_data()?;
@@ -116,6 +309,142 @@ pub fn distribute_mint<'info>(
         token_program.to_account_info(),
         holding_account.to_account_info(),
         fanout_mint_member_token_account_info,
-        dif_dist,
+        member_dist,
+    )?;
+    transfer_from_mint_holding(
+        fanout,
+        fanout.to_account_info(),
+        token_program.to_account_info(),
+        holding_account.to_account_info(),
+        fee_recipient,
+        fee,
+    )?;
+    if let Some(crank_reward_account) = crank_reward_account {
+        transfer_from_mint_holding(
+            fanout,
+            fanout.to_account_info(),
+            token_program.to_account_info(),
+            holding_account.to_account_info(),
+            crank_reward_account,
+            crank_reward,
+        )?;
+    }
+    Ok(())
+}
+
+// Inflow accrued on the fanout rather than to a member voucher.
+#[derive(Clone, Copy, PartialEq)]
+pub enum HeldInflow {
+    Reserve,
+    Treasury,
+}
+
+impl HeldInflow {
+    fn take_native(self, fanout: &mut Fanout) -> u64 {
+        match self {
+            HeldInflow::Reserve => std::mem::take(&mut fanout.reserve_unclaimed),
+            HeldInflow::Treasury => std::mem::take(&mut fanout.treasury_unclaimed),
+        }
+    }
+
+    fn take_mint(self, fanout_mint: &mut FanoutMint) -> u64 {
+        match self {
+            HeldInflow::Reserve => std::mem::take(&mut fanout_mint.reserve_unclaimed),
+            HeldInflow::Treasury => std::mem::take(&mut fanout_mint.treasury_unclaimed),
+        }
+    }
+}
+
+pub fn distribute_held_native<'info>(
+    holding_account: &mut UncheckedAccount<'info>,
+    fanout: &mut Account<'info, Fanout>,
+    recipient: AccountInfo<'info>,
+    rent: Sysvar<'info, anchor_lang::prelude::Rent>,
+    held: HeldInflow,
+) -> Result<()> {
+    if holding_account.key() != fanout.account_key {
+        return Err(HydraError::InvalidHoldingAccount.into());
+    }
+    let current_snapshot = holding_account.lamports();
+    let current_snapshot_less_min =
+        current_lamports(&rent, HOLDING_ACCOUNT_SIZE, current_snapshot)?;
+    update_inflow(fanout, current_snapshot_less_min)?;
+    let amount = held.take_native(fanout);
+    fanout.last_snapshot_amount = fanout
+        .last_snapshot_amount
+        .checked_sub(amount)
+        .or_arith_error()?;
+    emit!(Distributed {
+        fanout: fanout.key(),
+        membership_key: recipient.key(),
+        member: recipient.key(),
+        mint: None,
+        amount,
+        fee: 0,
+        crank_reward: 0,
+        total_inflow: fanout.total_inflow,
+    });
+    transfer_native(
+        holding_account.to_account_info(),
+        recipient,
+        current_snapshot,
+        amount,
+    )
+}
+
+pub fn distribute_held_mint<'info>(
+    fanout_mint: Account<'info, Mint>,
+    fanout_for_mint: &mut UncheckedAccount<'info>,
+    fanout_mint_recipient_token_account: &mut UncheckedAccount<'info>,
+    holding_account: &mut UncheckedAccount<'info>,
+    fanout: &mut Account<'info, Fanout>,
+    token_program: Program<'info, Token>,
+    recipient: &Pubkey,
+    held: HeldInflow,
+) -> Result<()> {
+    let mint = &fanout_mint;
+    let fanout_mint_recipient_token_account_info =
+        fanout_mint_recipient_token_account.to_account_info();
+    if fanout.unwrap_native_mint && mint.key() == spl_token::native_mint::id() {
+        return Err(HydraError::NativeMintUnwrapped.into());
+    }
+    assert_owned_by(fanout_for_mint, &crate::ID)?;
+    assert_owned_by(&fanout_mint_recipient_token_account_info, &Token::id())?;
+    assert_owned_by(holding_account, &anchor_spl::token::Token::id())?;
+    let fanout_for_mint_object =
+        &mut parse_fanout_mint(fanout_for_mint, &fanout.key(), &mint.key())?;
+    if holding_account.key() != fanout_for_mint_object.token_account {
+        return Err(HydraError::InvalidHoldingAccount.into());
+    }
+    let holding_account_ata = parse_token_account(holding_account, &fanout.key())?;
+    parse_token_account(&fanout_mint_recipient_token_account_info, recipient)?;
+    update_inflow_for_mint(fanout, fanout_for_mint_object, holding_account_ata.amount)?;
+    let amount = held.take_mint(fanout_for_mint_object);
+    if held == HeldInflow::Reserve && amount > 0 {
+        fanout.mints_with_reserve = fanout.mints_with_reserve.saturating_sub(1);
+    }
+    fanout_for_mint_object.last_snapshot_amount = fanout_for_mint_object
+        .last_snapshot_amount
+        .checked_sub(amount)
+        .or_arith_error()?;
+    emit!(Distributed {
+        fanout: fanout.key(),
+        membership_key: *recipient,
+        member: *recipient,
+        mint: Some(mint.key()),
+        amount,
+        fee: 0,
+        crank_reward: 0,
+        total_inflow: fanout_for_mint_object.total_inflow,
+    });
+    let mut fanout_for_mint_data: &mut [u8] = &mut fanout_for_mint.try_borrow_mut_data()?;
+    fanout_for_mint_object.try_serialize(&mut fanout_for_mint_data)?;
+    transfer_from_mint_holding(
+        fanout,
+        fanout.to_account_info(),
+        token_program.to_account_info(),
+        holding_account.to_account_info(),
+        fanout_mint_recipient_token_account_info,
+        amount,
     )
 }
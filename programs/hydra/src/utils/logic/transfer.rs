@@ -21,6 +21,7 @@ pub fn transfer_from_mint_holding<'info>(
 
         let seeds = [
             b"fanout-config".as_ref(),
+            fanout.base_seed(),
             fanout.name.as_bytes(),
             &[fanout.bump_seed],
         ];
@@ -29,6 +30,28 @@ pub fn transfer_from_mint_holding<'info>(
     Ok(())
 }
 
+pub fn unwrap_native_mint_holding<'info>(
+    fanout: &Fanout,
+    fanout_authority: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    source: AccountInfo<'info>,
+    dest: AccountInfo<'info>,
+) -> Result<()> {
+    let accounts = anchor_spl::token::CloseAccount {
+        account: source,
+        destination: dest,
+        authority: fanout_authority,
+    };
+    let cpi_ctx = CpiContext::new(token_program, accounts);
+    let seeds = [
+        b"fanout-config".as_ref(),
+        fanout.base_seed(),
+        fanout.name.as_bytes(),
+        &[fanout.bump_seed],
+    ];
+    anchor_spl::token::close_account(cpi_ctx.with_signer(&[&seeds]))
+}
+
 pub fn transfer_native<'info>(
     source: AccountInfo<'info>,
     dest: AccountInfo<'info>,
@@ -2,8 +2,9 @@ pub mod logic;
 pub mod validation;
 
 use crate::error::HydraError;
-use crate::state::{FanoutMembershipMintVoucher, FanoutMint, FANOUT_MINT_MEMBERSHIP_VOUCHER_SIZE};
-use crate::utils::validation::{assert_derivation, assert_owned_by};
+use crate::state::{Fanout, FanoutMembershipMintVoucher, FanoutMint, ACCOUNT_VERSION, FANOUT_MINT_MEMBERSHIP_VOUCHER_SIZE};
+use crate::utils::logic::calculation::{joins_late, update_inflow_for_mint};
+use crate::utils::validation::{assert_derivation, assert_owned_by, cmp_pubkeys};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::invoke_signed;
 use anchor_lang::solana_program::system_instruction;
@@ -58,6 +59,16 @@ pub fn create_or_allocate_account_raw<'a>(
     Ok(())
 }
 
+pub fn close_account_raw(account: &AccountInfo, destination: &AccountInfo) -> Result<()> {
+    **destination.lamports.borrow_mut() = destination
+        .lamports()
+        .checked_add(account.lamports())
+        .ok_or(HydraError::NumericalOverflow)?;
+    **account.lamports.borrow_mut() = 0;
+    account.try_borrow_mut_data()?.fill(0);
+    Ok(())
+}
+
 pub fn parse_fanout_mint(
     fanout_for_mint: &mut UncheckedAccount,
     fanout: &Pubkey,
@@ -80,6 +91,153 @@ pub fn parse_fanout_mint(
     Ok(fanout_for_mint_object)
 }
 
+// Instructions that must account for every mint voucher of a member take a group of accounts per
+// registered mint in the remaining accounts, each group starting with the FanoutMint. Mints
+// registered before they were counted must be migrated first, or they could be left out.
+pub fn parse_fanout_mint_groups<'a, 'info>(
+    fanout: &Fanout,
+    fanout_key: &Pubkey,
+    remaining_accounts: &'a [AccountInfo<'info>],
+    group_size: usize,
+) -> Result<Vec<(FanoutMint, &'a [AccountInfo<'info>])>> {
+    let mut groups: Vec<(FanoutMint, &'a [AccountInfo<'info>])> = Vec::new();
+    for group in remaining_accounts.chunks(group_size) {
+        if group.len() != group_size {
+            return Err(HydraError::MintAccountsRequired.into());
+        }
+        assert_owned_by(&group[0], &crate::ID)?;
+        let fanout_for_mint = {
+            let mut fanout_mint_data: &[u8] = &group[0].try_borrow_data()?;
+            FanoutMint::try_deserialize(&mut fanout_mint_data)?
+        };
+        if &fanout_for_mint.fanout != fanout_key
+            || groups.iter().any(|(other, _)| other.mint == fanout_for_mint.mint)
+        {
+            return Err(HydraError::InvalidFanoutForMint.into());
+        }
+        if !fanout_for_mint.counted {
+            return Err(HydraError::MintNotCounted.into());
+        }
+        groups.push((fanout_for_mint, group));
+    }
+    if groups.len() as u64 != fanout.total_mints {
+        return Err(HydraError::MintAccountsRequired.into());
+    }
+    Ok(groups)
+}
+
+// An unwrapped native mint's holding account is closed, so it holds nothing.
+pub fn holding_account_amount(
+    fanout_for_mint: &FanoutMint,
+    holding_account: &AccountInfo,
+) -> Result<u64> {
+    if !cmp_pubkeys(holding_account.key, &fanout_for_mint.token_account) {
+        return Err(HydraError::InvalidHoldingAccount.into());
+    }
+    if holding_account.data_is_empty() {
+        return Ok(0);
+    }
+    let mut holding_data: &[u8] = &holding_account.try_borrow_data()?;
+    Ok(TokenAccount::try_deserialize(&mut holding_data)?.amount)
+}
+
+// Starts a mint voucher at the mint's current inflow, snapshotted first, for a member that only
+// takes part in inflow from now on. A voucher left by an earlier membership of the key starts over.
+pub fn start_mint_membership_voucher<'info>(
+    fanout: &mut Fanout,
+    fanout_for_mint: &mut FanoutMint,
+    fanout_for_mint_info: &AccountInfo<'info>,
+    holding_account: &AccountInfo<'info>,
+    mint_voucher_info: &AccountInfo<'info>,
+    membership_key: &Pubkey,
+    rent: &Sysvar<'info, Rent>,
+    system_program: &Program<'info, System>,
+    payer: &AccountInfo<'info>,
+) -> Result<()> {
+    let current_snapshot = holding_account_amount(fanout_for_mint, holding_account)?;
+    update_inflow_for_mint(fanout, fanout_for_mint, current_snapshot)?;
+    let mut mint_voucher_account = UncheckedAccount::try_from(mint_voucher_info.clone());
+    let mut mint_voucher = parse_mint_membership_voucher(
+        &mut mint_voucher_account,
+        rent,
+        system_program,
+        payer,
+        membership_key,
+        fanout_for_mint_info.key,
+        &fanout_for_mint.mint,
+        &fanout_for_mint.fanout,
+        fanout_for_mint.total_inflow,
+    )?;
+    mint_voucher.last_inflow = fanout_for_mint.total_inflow;
+    mint_voucher.share_epoch = fanout_for_mint.share_epoch;
+    let mut mint_voucher_data: &mut [u8] = &mut mint_voucher_info.try_borrow_mut_data()?;
+    mint_voucher.try_serialize(&mut mint_voucher_data)?;
+    let mut fanout_for_mint_data: &mut [u8] = &mut fanout_for_mint_info.try_borrow_mut_data()?;
+    fanout_for_mint.try_serialize(&mut fanout_for_mint_data)
+}
+
+// Members joining late start every registered mint at its current inflow. Their mint vouchers are
+// created as they join from (fanout mint, holding account, mint membership voucher) groups, passed
+// in the remaining accounts after the native holding account.
+pub fn start_mint_vouchers_for_add<'info>(
+    fanout: &mut Account<'info, Fanout>,
+    membership_key: &Pubkey,
+    remaining_accounts: &[AccountInfo<'info>],
+    rent: &Sysvar<'info, Rent>,
+    system_program: &Program<'info, System>,
+    payer: &AccountInfo<'info>,
+) -> Result<()> {
+    if !joins_late(fanout) {
+        return Ok(());
+    }
+    let fanout_key = fanout.key();
+    let mint_accounts = remaining_accounts.get(1..).unwrap_or(&[]);
+    for (mut fanout_for_mint, group) in
+        parse_fanout_mint_groups(fanout, &fanout_key, mint_accounts, 3)?
+    {
+        start_mint_membership_voucher(
+            fanout,
+            &mut fanout_for_mint,
+            &group[0],
+            &group[1],
+            &group[2],
+            membership_key,
+            rent,
+            system_program,
+            payer,
+        )?;
+    }
+    Ok(())
+}
+
+// A member's mint voucher is empty until they receive a distribution of the mint.
+pub fn read_mint_membership_voucher(
+    account: &AccountInfo,
+    fanout_for_mint: &Pubkey,
+    membership_key: &Pubkey,
+    mint: &Pubkey,
+) -> Result<Option<FanoutMembershipMintVoucher>> {
+    assert_derivation(
+        &crate::ID,
+        account,
+        &[
+            b"fanout-membership",
+            fanout_for_mint.as_ref(),
+            membership_key.as_ref(),
+            mint.as_ref(),
+        ],
+        Some(HydraError::InvalidMembershipVoucher.into()),
+    )?;
+    if account.data_is_empty() {
+        return Ok(None);
+    }
+    assert_owned_by(account, &crate::ID)?;
+    let mut mint_voucher_data: &[u8] = &account.try_borrow_data()?;
+    Ok(Some(FanoutMembershipMintVoucher::try_deserialize(
+        &mut mint_voucher_data,
+    )?))
+}
+
 pub fn parse_token_account(
     account: &AccountInfo,
     owner: &Pubkey,
@@ -94,6 +252,41 @@ pub fn parse_token_account(
     Ok(account_object)
 }
 
+// Finds an optional account passed in the remaining accounts by its key.
+pub fn find_remaining_account<'info>(
+    remaining_accounts: &[AccountInfo<'info>],
+    key: &Pubkey,
+) -> Option<AccountInfo<'info>> {
+    remaining_accounts
+        .iter()
+        .find(|account| account.key == key)
+        .cloned()
+}
+
+// Finds an optional token account passed in the remaining accounts by its owner and mint.
+pub fn find_remaining_token_account<'info>(
+    remaining_accounts: &[AccountInfo<'info>],
+    owner: &Pubkey,
+    mint: &Pubkey,
+) -> Option<AccountInfo<'info>> {
+    remaining_accounts
+        .iter()
+        .find(|account| {
+            if account.owner != &anchor_spl::token::ID {
+                return false;
+            }
+            let data = match account.try_borrow_data() {
+                Ok(data) => data,
+                Err(_) => return false,
+            };
+            let mut account_data: &[u8] = &data;
+            TokenAccount::try_deserialize(&mut account_data)
+                .map(|token_account| &token_account.owner == owner && &token_account.mint == mint)
+                .unwrap_or(false)
+        })
+        .cloned()
+}
+
 pub fn parse_mint_membership_voucher<'info>(
     fanout_for_mint_membership_voucher: &mut UncheckedAccount<'info>,
     rent: &Sysvar<'info, anchor_lang::prelude::Rent>,
@@ -103,6 +296,7 @@ pub fn parse_mint_membership_voucher<'info>(
     fanout_for_mint: &Pubkey,
     fanout_mint: &Pubkey,
     fanout: &Pubkey,
+    initial_inflow: u64,
 ) -> Result<FanoutMembershipMintVoucher> {
     let account_info = fanout_for_mint_membership_voucher.to_account_info();
     let mint_membership_voucher_bump = assert_derivation(
@@ -138,8 +332,14 @@ pub fn parse_mint_membership_voucher<'info>(
         FanoutMembershipMintVoucher {
             fanout: *fanout,
             fanout_mint: *fanout_mint,
-            last_inflow: 0,
+            last_inflow: initial_inflow,
             bump_seed: mint_membership_voucher_bump,
+            total_inflow: 0,
+            total_inflow_migrated: true,
+            share_epoch: 0,
+            shares: 0,
+            version: ACCOUNT_VERSION,
+            last_distribution_window: None,
         }
     } else {
         let mut membership_data: &[u8] =
@@ -1,7 +1,13 @@
 use crate::error::HydraError;
-use crate::state::{Fanout, MembershipModel};
+use crate::utils::{
+    holding_account_amount, parse_fanout_mint_groups, read_mint_membership_voucher,
+};
+use crate::state::{
+    Fanout, FanoutMembershipMintVoucher, FanoutMembershipVoucher, FanoutMint, MembershipModel,
+};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::keccak::hashv;
 use anchor_spl::token::TokenAccount;
 use mpl_token_metadata::state::Metadata;
 use anchor_lang::solana_program::program_memory::{sol_memcmp};
@@ -67,7 +73,15 @@ pub fn assert_ata(
 }
 
 pub fn assert_shares_distributed(fanout: &Account<Fanout>) -> Result<()> {
-    if fanout.total_available_shares != 0 {
+    // Unclaimed merkle members and collection NFTs not yet joined are owed inflow since init, so
+    // distribution need not wait for them. Shares returned to the pool don't earn until they are
+    // allocated again.
+    if fanout.membership_model != MembershipModel::Merkle
+        && fanout.membership_collection.is_none()
+        && fanout.reserve.is_none()
+        && !fanout.shares_released
+        && fanout.total_available_shares != 0
+    {
         return Err(HydraError::SharesArentAtMax.into());
     }
     Ok(())
@@ -102,7 +116,9 @@ pub fn assert_distributed(
         return Err(HydraError::MustDistribute.into());
     }
     let instruction_id = match membership_model {
-        MembershipModel::Wallet => [252, 168, 167, 66, 40, 201, 182, 163],
+        MembershipModel::Wallet | MembershipModel::Merkle => {
+            [252, 168, 167, 66, 40, 201, 182, 163]
+        }
         MembershipModel::NFT => [108, 240, 68, 81, 144, 83, 58, 153],
         MembershipModel::Token => [126, 105, 46, 135, 28, 36, 117, 212],
     };
@@ -115,6 +131,73 @@ pub fn assert_distributed(
     Ok(())
 }
 
+// A distribution below the minimum threshold can precede a settle-first instruction without
+// moving the voucher, so share changes also check it caught up with the fanout.
+pub fn assert_settled(fanout: &Fanout, membership_voucher: &FanoutMembershipVoucher) -> Result<()> {
+    if membership_voucher.last_inflow != fanout.total_inflow {
+        return Err(HydraError::MustDistribute.into());
+    }
+    Ok(())
+}
+
+// Mint inflow is split by the shares held when it is distributed, so share changes also require
+// every mint voucher to have caught up with its mint, including inflow not yet snapshotted.
+pub fn assert_mint_settled(
+    fanout_for_mint: &FanoutMint,
+    holding_account: &AccountInfo,
+    mint_voucher: Option<&FanoutMembershipMintVoucher>,
+) -> Result<()> {
+    // Mints with their own share table don't pay by fanout shares
+    if fanout_for_mint.total_shares.is_some() {
+        return Ok(());
+    }
+    let holding_amount = holding_account_amount(fanout_for_mint, holding_account)?;
+    let last_inflow = mint_voucher.map_or(0, |mint_voucher| mint_voucher.last_inflow);
+    if holding_amount > fanout_for_mint.last_snapshot_amount
+        || last_inflow != fanout_for_mint.total_inflow
+    {
+        return Err(HydraError::MustDistribute.into());
+    }
+    Ok(())
+}
+
+// A member's scheduled shares replace their previous shares, so every mint splitting by fanout
+// shares must have switched with the fanout and settled the members changed at that switch, and
+// the member's mint voucher must be settled to the current epoch. Remaining accounts are
+// (fanout mint, mint membership voucher) for every registered mint.
+pub fn assert_mints_ready_for_share_change(
+    fanout: &Fanout,
+    fanout_key: &Pubkey,
+    membership_key: &Pubkey,
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
+    let mint_groups = parse_fanout_mint_groups(fanout, fanout_key, remaining_accounts, 2)?;
+    for (fanout_for_mint, group) in mint_groups {
+        if fanout_for_mint.total_shares.is_some() {
+            continue;
+        }
+        if fanout_for_mint.share_epoch != fanout.share_epoch
+            || fanout_for_mint.pending_share_changes > 0
+        {
+            return Err(HydraError::ShareChangePending.into());
+        }
+        let mint_voucher = read_mint_membership_voucher(
+            &group[1],
+            group[0].key,
+            membership_key,
+            &fanout_for_mint.mint,
+        )?;
+        let settled = match mint_voucher {
+            Some(mint_voucher) => mint_voucher.share_epoch == fanout_for_mint.share_epoch,
+            None => fanout_for_mint.total_inflow == 0,
+        };
+        if !settled {
+            return Err(HydraError::MustDistribute.into());
+        }
+    }
+    Ok(())
+}
+
 pub fn assert_valid_metadata(
     metadata_account: &AccountInfo,
     mint: &AccountInfo,
@@ -126,6 +209,32 @@ pub fn assert_valid_metadata(
     Ok(meta)
 }
 
+pub fn assert_verified_collection(metadata: &Metadata, collection: &Pubkey) -> Result<()> {
+    match &metadata.collection {
+        Some(c) if c.verified && cmp_pubkeys(&c.key, collection) => Ok(()),
+        _ => Err(HydraError::InvalidCollection.into()),
+    }
+}
+
+pub fn membership_leaf(membership_key: &Pubkey, shares: u64) -> [u8; 32] {
+    hashv(&[membership_key.as_ref(), &shares.to_le_bytes()]).to_bytes()
+}
+
+pub fn assert_valid_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> Result<()> {
+    let mut computed = leaf;
+    for node in proof {
+        computed = if computed <= *node {
+            hashv(&[&computed, node]).to_bytes()
+        } else {
+            hashv(&[node, &computed]).to_bytes()
+        };
+    }
+    if computed != *root {
+        return Err(HydraError::InvalidProof.into());
+    }
+    Ok(())
+}
+
 pub fn assert_owned_by_one(account: &AccountInfo, owners: Vec<&Pubkey>) -> Result<()> {
     for o in owners {
         let res = assert_owned_by(account, o);
@@ -160,4 +269,26 @@ mod tests {
 
         assert_eq!(e.is_ok(), true);
     }
+
+    #[test]
+    fn test_merkle_proof() {
+        let a = membership_leaf(&Pubkey::new_unique(), 5);
+        let b = membership_leaf(&Pubkey::new_unique(), 1);
+        let c = membership_leaf(&Pubkey::new_unique(), 1);
+        let ab = if a <= b {
+            hashv(&[&a, &b]).to_bytes()
+        } else {
+            hashv(&[&b, &a]).to_bytes()
+        };
+        let root = if ab <= c {
+            hashv(&[&ab, &c]).to_bytes()
+        } else {
+            hashv(&[&c, &ab]).to_bytes()
+        };
+
+        assert!(assert_valid_proof(&[b, c], &root, a).is_ok());
+        assert!(assert_valid_proof(&[ab], &root, c).is_ok());
+        assert!(assert_valid_proof(&[a, c], &root, a).is_err());
+        assert!(assert_valid_proof(&[], &root, a).is_err());
+    }
 }
@@ -0,0 +1,505 @@
+import {
+    Account,
+    Connection,
+    Keypair,
+    LAMPORTS_PER_SOL,
+    PublicKey,
+    TransactionInstruction,
+} from "@solana/web3.js";
+import {NATIVE_MINT, Token, TOKEN_PROGRAM_ID} from "@solana/spl-token";
+import {NodeWallet} from "@project-serum/common";
+import {expect, use} from "chai";
+import ChaiAsPromised from "chai-as-promised";
+import {
+    createProcessDistributeReserveInstruction,
+    createProcessSetCrankRewardInstruction,
+    createProcessSetDistributionWindowInstruction,
+    createProcessSetFeeInstruction,
+    createProcessSetMinDistributionInstruction,
+    createProcessSetReserveInstruction,
+    createProcessSetUnwrapNativeMintInstruction,
+    createProcessUnwrapNativeMintInstruction,
+    DistributionWindow,
+    DistributionWindowScope,
+    Fanout,
+    FanoutClient,
+    FanoutMembershipVoucher,
+    MembershipModel,
+} from "../packages/sdk/src";
+import {airdrop, LOCALHOST} from "@metaplex-foundation/amman";
+import {builtWalletFanout} from "./utils/scenarios";
+import {sendOk, withRemainingAccounts} from "./utils/program";
+
+use(ChaiAsPromised);
+
+describe("distribution", async () => {
+    const connection = new Connection(LOCALHOST, "confirmed");
+    let authorityWallet: Keypair;
+    let fanoutSdk: FanoutClient;
+    let distBot: Keypair;
+    beforeEach(async () => {
+        authorityWallet = Keypair.generate();
+        await airdrop(connection, authorityWallet.publicKey, LAMPORTS_PER_SOL * 10);
+        fanoutSdk = new FanoutClient(
+            connection,
+            new NodeWallet(new Account(authorityWallet.secretKey))
+        );
+        distBot = Keypair.generate();
+        await airdrop(connection, distBot.publicKey, 1);
+    });
+
+    async function sendAsAuthority(instruction: TransactionInstruction) {
+        return await sendOk(
+            fanoutSdk,
+            [instruction],
+            [authorityWallet],
+            authorityWallet.publicKey
+        );
+    }
+
+    async function distributeNative(
+        fanout: PublicKey,
+        member: PublicKey,
+        payer: Keypair,
+        remainingAccounts: PublicKey[] = []
+    ) {
+        const dist = await fanoutSdk.distributeWalletMemberInstructions({
+            distributeForMint: false,
+            member,
+            fanout,
+            payer: payer.publicKey,
+        });
+        const tx = await fanoutSdk.sendInstructions(
+            withRemainingAccounts(dist.instructions, remainingAccounts),
+            [payer],
+            payer.publicKey
+        );
+        return tx.RpcResponseAndContext.value.err;
+    }
+
+    it("Pays the fee to the fee recipient", async () => {
+        const builtFanout = await builtWalletFanout(fanoutSdk, 100, 2);
+        const feeRecipient = Keypair.generate();
+        expect(
+            await sendAsAuthority(
+                createProcessSetFeeInstruction(
+                    {
+                        authority: authorityWallet.publicKey,
+                        fanout: builtFanout.fanout,
+                        feeRecipient: feeRecipient.publicKey,
+                    },
+                    {feeBasisPoints: 250}
+                )
+            )
+        ).to.be.null;
+        await airdrop(connection, builtFanout.fanoutAccountData.accountKey, 1);
+        const member = builtFanout.members[0].wallet.publicKey;
+        // The fee recipient has to be passed once a fee is set
+        expect(
+            await distributeNative(builtFanout.fanout, member, distBot)
+        ).to.not.be.null;
+        expect(
+            await distributeNative(builtFanout.fanout, member, distBot, [
+                feeRecipient.publicKey,
+            ])
+        ).to.be.null;
+        const fee = (0.5 * LAMPORTS_PER_SOL * 250) / 10000;
+        expect(await connection.getBalance(feeRecipient.publicKey)).to.equal(fee);
+        expect(await connection.getBalance(member)).to.equal(
+            0.5 * LAMPORTS_PER_SOL - fee
+        );
+        const fanoutAccount = await Fanout.fromAccountAddress(
+            connection,
+            builtFanout.fanout
+        );
+        expect(fanoutAccount.totalFees.toString()).to.equal(fee.toString());
+    });
+
+    it("Rewards the crank from the member's payout", async () => {
+        const builtFanout = await builtWalletFanout(fanoutSdk, 100, 2);
+        expect(
+            await sendAsAuthority(
+                createProcessSetCrankRewardInstruction(
+                    {
+                        authority: authorityWallet.publicKey,
+                        fanout: builtFanout.fanout,
+                    },
+                    {crankReward: {__kind: "Fixed", lamports: 5000}}
+                )
+            )
+        ).to.be.null;
+        await airdrop(connection, builtFanout.fanoutAccountData.accountKey, 1);
+        const member = builtFanout.members[0].wallet.publicKey;
+        expect(await distributeNative(builtFanout.fanout, member, distBot)).to
+            .be.null;
+        expect(await connection.getBalance(member)).to.equal(
+            0.5 * LAMPORTS_PER_SOL - 5000
+        );
+        const fanoutAccount = await Fanout.fromAccountAddress(
+            connection,
+            builtFanout.fanout
+        );
+        expect(fanoutAccount.totalCrankRewards.toString()).to.equal("5000");
+    });
+
+    it("Holds the inflow of unallocated shares for the reserve", async () => {
+        const init = await fanoutSdk.initializeFanout({
+            totalShares: 100,
+            name: `Test${Date.now()}`,
+            membershipModel: MembershipModel.Wallet,
+        });
+        const member = Keypair.generate();
+        await fanoutSdk.addMemberWallet({
+            fanout: init.fanout,
+            fanoutNativeAccount: init.nativeAccount,
+            membershipKey: member.publicKey,
+            shares: 50,
+        });
+        const reserve = Keypair.generate();
+        expect(
+            await sendAsAuthority(
+                createProcessSetReserveInstruction(
+                    {
+                        authority: authorityWallet.publicKey,
+                        fanout: init.fanout,
+                        reserve: reserve.publicKey,
+                    },
+                    {enabled: true}
+                )
+            )
+        ).to.be.null;
+        await airdrop(connection, init.nativeAccount, 1);
+        expect(await distributeNative(init.fanout, member.publicKey, distBot)).to
+            .be.null;
+        expect(await connection.getBalance(member.publicKey)).to.equal(
+            0.5 * LAMPORTS_PER_SOL
+        );
+        const [fanoutForMint] = await FanoutClient.fanoutForMintKey(
+            init.fanout,
+            NATIVE_MINT
+        );
+        expect(
+            await sendAsAuthority(
+                createProcessDistributeReserveInstruction(
+                    {
+                        payer: authorityWallet.publicKey,
+                        reserve: reserve.publicKey,
+                        fanout: init.fanout,
+                        holdingAccount: init.nativeAccount,
+                        fanoutForMint,
+                        fanoutMint: NATIVE_MINT,
+                        fanoutMintReserveTokenAccount: reserve.publicKey,
+                    },
+                    {distributeForMint: false}
+                )
+            )
+        ).to.be.null;
+        expect(await connection.getBalance(reserve.publicKey)).to.equal(
+            0.5 * LAMPORTS_PER_SOL
+        );
+        // Members joining later snapshot the holding account and start from its inflow
+        await airdrop(connection, init.nativeAccount, 1);
+        const lateMember = Keypair.generate();
+        const add = await fanoutSdk.addMemberWalletInstructions({
+            fanout: init.fanout,
+            fanoutNativeAccount: init.nativeAccount,
+            membershipKey: lateMember.publicKey,
+            shares: 25,
+        });
+        expect(
+            (
+                await fanoutSdk.sendInstructions(
+                    add.instructions,
+                    [],
+                    authorityWallet.publicKey
+                )
+            ).RpcResponseAndContext.value.err
+        ).to.not.be.null;
+        expect(
+            await sendOk(
+                fanoutSdk,
+                withRemainingAccounts(add.instructions, [init.nativeAccount]),
+                [authorityWallet],
+                authorityWallet.publicKey
+            )
+        ).to.be.null;
+        const fanoutAccount = await Fanout.fromAccountAddress(
+            connection,
+            init.fanout
+        );
+        const voucher = await FanoutMembershipVoucher.fromAccountAddress(
+            connection,
+            add.output.membershipAccount
+        );
+        expect(fanoutAccount.totalInflow.toString()).to.equal(
+            (2 * LAMPORTS_PER_SOL).toString()
+        );
+        expect(voucher.lastInflow.toString()).to.equal(
+            fanoutAccount.totalInflow.toString()
+        );
+        expect(fanoutAccount.reserveUnclaimed.toString()).to.equal(
+            (0.5 * LAMPORTS_PER_SOL).toString()
+        );
+    });
+
+    it("Skips cranked payouts below the minimum distribution", async () => {
+        const builtFanout = await builtWalletFanout(fanoutSdk, 100, 2);
+        expect(
+            await sendAsAuthority(
+                createProcessSetMinDistributionInstruction(
+                    {
+                        authority: authorityWallet.publicKey,
+                        fanout: builtFanout.fanout,
+                    },
+                    {minDistribution: LAMPORTS_PER_SOL}
+                )
+            )
+        ).to.be.null;
+        await airdrop(connection, builtFanout.fanoutAccountData.accountKey, 1);
+        const member = builtFanout.members[0];
+        expect(
+            await distributeNative(
+                builtFanout.fanout,
+                member.wallet.publicKey,
+                distBot
+            )
+        ).to.be.null;
+        expect(await connection.getBalance(member.wallet.publicKey)).to.equal(0);
+        const voucher = await FanoutMembershipVoucher.fromAccountAddress(
+            connection,
+            member.voucher
+        );
+        expect(voucher.lastInflow.toString()).to.equal("0");
+        // The authority can still settle the member
+        expect(
+            await distributeNative(
+                builtFanout.fanout,
+                member.wallet.publicKey,
+                authorityWallet
+            )
+        ).to.be.null;
+        expect(await connection.getBalance(member.wallet.publicKey)).to.equal(
+            0.5 * LAMPORTS_PER_SOL
+        );
+    });
+
+    it("Pays wrapped SOL out as native inflow once unwrapped", async () => {
+        const builtFanout = await builtWalletFanout(fanoutSdk, 100, 2);
+        const {fanoutForMint, tokenAccount} =
+            await fanoutSdk.initializeFanoutForMint({
+                fanout: builtFanout.fanout,
+                mint: NATIVE_MINT,
+            });
+        const wrapSol = async (lamports: number) => {
+            const wrapped = await Token.createWrappedNativeAccount(
+                connection,
+                TOKEN_PROGRAM_ID,
+                authorityWallet.publicKey,
+                authorityWallet,
+                lamports
+            );
+            await new Token(
+                connection,
+                NATIVE_MINT,
+                TOKEN_PROGRAM_ID,
+                authorityWallet
+            ).transfer(wrapped, tokenAccount, authorityWallet, [], lamports);
+        };
+        const memberAccounts = (
+            await Promise.all(
+                builtFanout.members.map(async (member) => {
+                    const [mintVoucher] = await FanoutClient.mintMembershipVoucher(
+                        fanoutForMint,
+                        member.wallet.publicKey,
+                        NATIVE_MINT
+                    );
+                    return [member.voucher, mintVoucher];
+                })
+            )
+        ).flat();
+        const enableUnwrap = () =>
+            sendOk(
+                fanoutSdk,
+                withRemainingAccounts(
+                    [
+                        createProcessSetUnwrapNativeMintInstruction(
+                            {
+                                authority: authorityWallet.publicKey,
+                                fanout: builtFanout.fanout,
+                                nativeFanoutForMint: fanoutForMint,
+                            },
+                            {unwrapNativeMint: true}
+                        ),
+                    ],
+                    [tokenAccount, ...memberAccounts]
+                ),
+                [authorityWallet],
+                authorityWallet.publicKey
+            );
+        await wrapSol(LAMPORTS_PER_SOL);
+        // wSOL still owed to members would be counted again as native inflow
+        expect(await enableUnwrap()).to.not.be.null;
+        for (const member of builtFanout.members) {
+            const dist = await fanoutSdk.distributeWalletMemberInstructions({
+                distributeForMint: true,
+                member: member.wallet.publicKey,
+                fanout: builtFanout.fanout,
+                payer: authorityWallet.publicKey,
+                fanoutMint: NATIVE_MINT,
+            });
+            expect(
+                await sendOk(
+                    fanoutSdk,
+                    dist.instructions,
+                    [authorityWallet],
+                    authorityWallet.publicKey
+                )
+            ).to.be.null;
+        }
+        expect(await enableUnwrap()).to.be.null;
+        await wrapSol(LAMPORTS_PER_SOL);
+        // Closing the holding account moves its rent along with the wrapped lamports
+        const wrappedLamports = await connection.getBalance(tokenAccount);
+        expect(
+            await sendOk(
+                fanoutSdk,
+                [
+                    createProcessUnwrapNativeMintInstruction({
+                        payer: distBot.publicKey,
+                        fanout: builtFanout.fanout,
+                        holdingAccount: builtFanout.fanoutAccountData.accountKey,
+                        nativeMintHoldingAccount: tokenAccount,
+                    }),
+                ],
+                [distBot],
+                distBot.publicKey
+            )
+        ).to.be.null;
+        expect(await connection.getAccountInfo(tokenAccount)).to.be.null;
+        const member = builtFanout.members[0].wallet.publicKey;
+        expect(await distributeNative(builtFanout.fanout, member, distBot)).to.be
+            .null;
+        expect(await connection.getBalance(member)).to.equal(wrappedLamports / 2);
+    });
+
+    it("Distributes from fanouts scoped to a base key", async () => {
+        const name = `Test${Date.now()}`;
+        const bases = [Keypair.generate(), Keypair.generate()];
+        // Each base derives its own fanout for the same name
+        const [init, other] = await Promise.all(
+            bases.map((base) =>
+                fanoutSdk.initializeFanout({
+                    totalShares: 100,
+                    name,
+                    membershipModel: MembershipModel.Wallet,
+                    base,
+                })
+            )
+        );
+        const [fanoutKey] = await FanoutClient.fanoutKey(
+            name,
+            FanoutClient.ID,
+            bases[0].publicKey
+        );
+        expect(init.fanout.toBase58()).to.equal(fanoutKey.toBase58());
+        expect(other.fanout.toBase58()).to.not.equal(init.fanout.toBase58());
+        const fanoutAccount = await Fanout.fromAccountAddress(
+            connection,
+            init.fanout
+        );
+        expect(fanoutAccount.base?.toBase58()).to.equal(
+            bases[0].publicKey.toBase58()
+        );
+        const member = Keypair.generate();
+        await fanoutSdk.addMemberWallet({
+            fanout: init.fanout,
+            fanoutNativeAccount: init.nativeAccount,
+            membershipKey: member.publicKey,
+            shares: 100,
+        });
+        await airdrop(connection, init.nativeAccount, 1);
+        expect(await distributeNative(init.fanout, member.publicKey, distBot)).to
+            .be.null;
+        expect(await connection.getBalance(member.publicKey)).to.equal(
+            LAMPORTS_PER_SOL
+        );
+    });
+
+    describe("Distribution windows", () => {
+        // Local validators stay in the first window of a million epochs
+        const window: DistributionWindow = {__kind: "Epochs", epochs: 1_000_000};
+
+        async function setWindow(
+            fanout: PublicKey,
+            scope: DistributionWindowScope
+        ) {
+            expect(
+                await sendAsAuthority(
+                    createProcessSetDistributionWindowInstruction(
+                        {authority: authorityWallet.publicKey, fanout},
+                        {distributionWindow: window, distributionWindowScope: scope}
+                    )
+                )
+            ).to.be.null;
+        }
+
+        it("Snapshots cranked inflow once per window for the fanout", async () => {
+            const builtFanout = await builtWalletFanout(fanoutSdk, 100, 2);
+            await setWindow(builtFanout.fanout, DistributionWindowScope.Fanout);
+            const [member1, member2] = builtFanout.members.map(
+                (m) => m.wallet.publicKey
+            );
+            await airdrop(connection, builtFanout.fanoutAccountData.accountKey, 1);
+            expect(await distributeNative(builtFanout.fanout, member1, distBot)).to
+                .be.null;
+            await airdrop(connection, builtFanout.fanoutAccountData.accountKey, 1);
+            // Later cranks in the window are rejected
+            expect(await distributeNative(builtFanout.fanout, member2, distBot)).to
+                .not.be.null;
+            expect(await connection.getBalance(member1)).to.equal(
+                0.5 * LAMPORTS_PER_SOL
+            );
+            expect(await connection.getBalance(member2)).to.equal(0);
+            // Members settling their own payout are not limited
+            await airdrop(connection, member2, 1);
+            expect(
+                await distributeNative(
+                    builtFanout.fanout,
+                    member2,
+                    builtFanout.members[1].wallet
+                )
+            ).to.be.null;
+        });
+
+        it("Pays each member once per window", async () => {
+            const builtFanout = await builtWalletFanout(fanoutSdk, 100, 2);
+            await setWindow(builtFanout.fanout, DistributionWindowScope.Member);
+            const [member1, member2] = builtFanout.members.map(
+                (m) => m.wallet.publicKey
+            );
+            await airdrop(connection, builtFanout.fanoutAccountData.accountKey, 1);
+            expect(await distributeNative(builtFanout.fanout, member1, distBot)).to
+                .be.null;
+            await airdrop(connection, builtFanout.fanoutAccountData.accountKey, 1);
+            expect(await distributeNative(builtFanout.fanout, member1, distBot)).to
+                .not.be.null;
+            expect(await distributeNative(builtFanout.fanout, member2, distBot)).to
+                .be.null;
+            expect(await connection.getBalance(member1)).to.equal(
+                0.5 * LAMPORTS_PER_SOL
+            );
+            expect(await connection.getBalance(member2)).to.equal(
+                LAMPORTS_PER_SOL
+            );
+            // Members settling their own payout are not limited
+            await airdrop(connection, member1, 1);
+            expect(
+                await distributeNative(
+                    builtFanout.fanout,
+                    member1,
+                    builtFanout.members[0].wallet
+                )
+            ).to.be.null;
+        });
+    });
+});
@@ -0,0 +1,865 @@
+import {
+    Account,
+    Connection,
+    Keypair,
+    LAMPORTS_PER_SOL,
+    PublicKey,
+    SYSVAR_INSTRUCTIONS_PUBKEY,
+    TransactionInstruction,
+} from "@solana/web3.js";
+import {
+    ASSOCIATED_TOKEN_PROGRAM_ID,
+    Token,
+    TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import {NodeWallet} from "@project-serum/common";
+import {expect, use} from "chai";
+import ChaiAsPromised from "chai-as-promised";
+import {
+    createProcessAddMemberNftCollectionInstruction,
+    createProcessInitForMembershipMintInstruction,
+    createProcessMemberTransferSharesInstruction,
+    createProcessMigrateMintVoucherInstruction,
+    createProcessReleaseExpiredMemberInstruction,
+    createProcessResignInstruction,
+    createProcessRotateMemberKeyInstruction,
+    createProcessScheduleShareChangeInstruction,
+    createProcessSetFanoutMetadataInstruction,
+    createProcessSetMemberExpiryInstruction,
+    createProcessSetMemberLabelInstruction,
+    createProcessSetMembershipCollectionInstruction,
+    createProcessSetMemberTransfersInstruction,
+    createProcessSetMintMemberSharesInstruction,
+    createProcessSetMintShareTableInstruction,
+    createProcessSetPayoutDestinationInstruction,
+    createProcessSetWeightedTokenMemberStakeInstruction,
+    createProcessUnstakeWeightedInstruction,
+    Fanout,
+    FanoutClient,
+    FanoutMembershipMint,
+    FanoutMembershipMintVoucher,
+    FanoutMembershipStake,
+    FanoutMembershipVoucher,
+    FanoutMetadata,
+    FanoutMint,
+    MembershipModel,
+} from "../packages/sdk/src";
+import {airdrop, LOCALHOST} from "@metaplex-foundation/amman";
+import {Collection, DataV2} from "@metaplex-foundation/mpl-token-metadata";
+import {builtWalletFanout} from "./utils/scenarios";
+import {createMasterEdition, verifyCollection} from "./utils/metaplex";
+import {sendOk, withRemainingAccounts} from "./utils/program";
+
+use(ChaiAsPromised);
+
+describe("membership", async () => {
+    const connection = new Connection(LOCALHOST, "confirmed");
+    let authorityWallet: Keypair;
+    let fanoutSdk: FanoutClient;
+    beforeEach(async () => {
+        authorityWallet = Keypair.generate();
+        await airdrop(connection, authorityWallet.publicKey, LAMPORTS_PER_SOL * 10);
+        fanoutSdk = new FanoutClient(
+            connection,
+            new NodeWallet(new Account(authorityWallet.secretKey))
+        );
+    });
+
+    async function sendAsAuthority(instruction: TransactionInstruction) {
+        return await sendOk(
+            fanoutSdk,
+            [instruction],
+            [authorityWallet],
+            authorityWallet.publicKey
+        );
+    }
+
+    async function distributeNative(
+        fanout: PublicKey,
+        member: PublicKey,
+        remainingAccounts: PublicKey[] = []
+    ) {
+        const dist = await fanoutSdk.distributeWalletMemberInstructions({
+            distributeForMint: false,
+            member,
+            fanout,
+            payer: authorityWallet.publicKey,
+        });
+        return await sendOk(
+            fanoutSdk,
+            withRemainingAccounts(dist.instructions, remainingAccounts),
+            [authorityWallet],
+            authorityWallet.publicKey
+        );
+    }
+
+    async function chainTime() {
+        return (await connection.getBlockTime(await connection.getSlot()))!;
+    }
+
+    // Expiries and share schedules compare against the validator clock
+    async function waitUntil(unixTimestamp: number) {
+        while ((await chainTime()) < unixTimestamp) {
+            await new Promise((resolve) => setTimeout(resolve, 500));
+        }
+    }
+
+    it("Pays members to their payout destination", async () => {
+        const builtFanout = await builtWalletFanout(fanoutSdk, 100, 2);
+        const member = builtFanout.members[0];
+        const destination = Keypair.generate();
+        const setDestination = createProcessSetPayoutDestinationInstruction(
+            {
+                member: member.wallet.publicKey,
+                fanout: builtFanout.fanout,
+                membershipVoucher: member.voucher,
+            },
+            {payoutDestination: destination.publicKey}
+        );
+        expect(
+            await sendOk(
+                fanoutSdk,
+                [setDestination],
+                [authorityWallet, member.wallet],
+                authorityWallet.publicKey
+            )
+        ).to.be.null;
+        await airdrop(connection, builtFanout.fanoutAccountData.accountKey, 1);
+        // The destination has to be passed once it is set
+        expect(
+            await distributeNative(builtFanout.fanout, member.wallet.publicKey)
+        ).to.not.be.null;
+        expect(
+            await distributeNative(builtFanout.fanout, member.wallet.publicKey, [
+                destination.publicKey,
+            ])
+        ).to.be.null;
+        expect(await connection.getBalance(destination.publicKey)).to.equal(
+            0.5 * LAMPORTS_PER_SOL
+        );
+        expect(await connection.getBalance(member.wallet.publicKey)).to.equal(0);
+    });
+
+    it("Moves a member's voucher to a new key", async () => {
+        const builtFanout = await builtWalletFanout(fanoutSdk, 100, 2);
+        const member = builtFanout.members[0];
+        const newMember = Keypair.generate();
+        const [newVoucher] = await FanoutClient.membershipVoucher(
+            builtFanout.fanout,
+            newMember.publicKey
+        );
+        expect(
+            await sendAsAuthority(
+                createProcessRotateMemberKeyInstruction({
+                    signer: authorityWallet.publicKey,
+                    member: member.wallet.publicKey,
+                    newMember: newMember.publicKey,
+                    fanout: builtFanout.fanout,
+                    membershipVoucher: member.voucher,
+                    newMembershipVoucher: newVoucher,
+                    rentPayer: authorityWallet.publicKey,
+                })
+            )
+        ).to.be.null;
+        expect(await connection.getAccountInfo(member.voucher)).to.be.null;
+        const voucher = await FanoutMembershipVoucher.fromAccountAddress(
+            connection,
+            newVoucher
+        );
+        expect(voucher.shares.toString()).to.equal("50");
+        await airdrop(connection, builtFanout.fanoutAccountData.accountKey, 1);
+        expect(await distributeNative(builtFanout.fanout, newMember.publicKey)).to
+            .be.null;
+        expect(await connection.getBalance(newMember.publicKey)).to.equal(
+            0.5 * LAMPORTS_PER_SOL
+        );
+    });
+
+    it("Lets members transfer their own shares", async () => {
+        const builtFanout = await builtWalletFanout(fanoutSdk, 100, 2);
+        const member = builtFanout.members[0];
+        const recipient = Keypair.generate();
+        const [recipientVoucher] = await FanoutClient.membershipVoucher(
+            builtFanout.fanout,
+            recipient.publicKey
+        );
+        expect(
+            await sendAsAuthority(
+                createProcessSetMemberTransfersInstruction(
+                    {
+                        authority: authorityWallet.publicKey,
+                        fanout: builtFanout.fanout,
+                    },
+                    {memberTransfersEnabled: true}
+                )
+            )
+        ).to.be.null;
+        await airdrop(connection, member.wallet.publicKey, 1);
+        // The sender settles in the instruction right before the transfer
+        const dist = await fanoutSdk.distributeWalletMemberInstructions({
+            distributeForMint: false,
+            member: member.wallet.publicKey,
+            fanout: builtFanout.fanout,
+            payer: member.wallet.publicKey,
+        });
+        const transfer = createProcessMemberTransferSharesInstruction(
+            {
+                fromMember: member.wallet.publicKey,
+                toMember: recipient.publicKey,
+                fanout: builtFanout.fanout,
+                fromMembershipAccount: member.voucher,
+                toMembershipAccount: recipientVoucher,
+                instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+            },
+            {shares: 20}
+        );
+        expect(
+            await sendOk(
+                fanoutSdk,
+                [...dist.instructions, transfer],
+                [member.wallet],
+                member.wallet.publicKey
+            )
+        ).to.be.null;
+        const fromVoucher = await FanoutMembershipVoucher.fromAccountAddress(
+            connection,
+            member.voucher
+        );
+        const toVoucher = await FanoutMembershipVoucher.fromAccountAddress(
+            connection,
+            recipientVoucher
+        );
+        expect(fromVoucher.shares.toString()).to.equal("30");
+        expect(toVoucher.shares.toString()).to.equal("20");
+    });
+
+    it("Lets members resign after settling", async () => {
+        const builtFanout = await builtWalletFanout(fanoutSdk, 100, 2);
+        const member = builtFanout.members[0];
+        await airdrop(connection, builtFanout.fanoutAccountData.accountKey, 1);
+        const resign = createProcessResignInstruction({
+            member: member.wallet.publicKey,
+            fanout: builtFanout.fanout,
+            membershipVoucher: member.voucher,
+            rentPayer: authorityWallet.publicKey,
+            instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        });
+        // Members holding shares have to settle in the instruction right before resigning
+        expect(
+            await sendOk(
+                fanoutSdk,
+                [resign],
+                [authorityWallet, member.wallet],
+                authorityWallet.publicKey
+            )
+        ).to.not.be.null;
+        const dist = await fanoutSdk.distributeWalletMemberInstructions({
+            distributeForMint: false,
+            member: member.wallet.publicKey,
+            fanout: builtFanout.fanout,
+            payer: member.wallet.publicKey,
+        });
+        expect(
+            await sendOk(
+                fanoutSdk,
+                [...dist.instructions, resign],
+                [authorityWallet, member.wallet],
+                authorityWallet.publicKey
+            )
+        ).to.be.null;
+        expect(await connection.getBalance(member.wallet.publicKey)).to.equal(
+            0.5 * LAMPORTS_PER_SOL
+        );
+        expect(await connection.getAccountInfo(member.voucher)).to.be.null;
+        const fanoutAccount = await Fanout.fromAccountAddress(
+            connection,
+            builtFanout.fanout
+        );
+        expect(fanoutAccount.totalMembers.toString()).to.equal("1");
+        expect(fanoutAccount.totalAvailableShares.toString()).to.equal("50");
+    });
+
+    it("Switches expired members to no shares at the expiry", async () => {
+        const builtFanout = await builtWalletFanout(fanoutSdk, 100, 2);
+        const member = builtFanout.members[0];
+        const other = builtFanout.members[1];
+        const expiresAt = (await chainTime()) + 5;
+        expect(
+            await sendAsAuthority(
+                createProcessSetMemberExpiryInstruction(
+                    {
+                        authority: authorityWallet.publicKey,
+                        fanout: builtFanout.fanout,
+                        membershipVoucher: member.voucher,
+                    },
+                    {expiresAt}
+                )
+            )
+        ).to.be.null;
+        await airdrop(connection, builtFanout.fanoutAccountData.accountKey, 1);
+        await waitUntil(expiresAt);
+        // Anyone can snapshot the fanout for the switch to land at the expiry
+        expect(
+            await sendAsAuthority(
+                createProcessReleaseExpiredMemberInstruction({
+                    fanout: builtFanout.fanout,
+                    membershipVoucher: member.voucher,
+                    holdingAccount: builtFanout.fanoutAccountData.accountKey,
+                })
+            )
+        ).to.be.null;
+        await airdrop(connection, builtFanout.fanoutAccountData.accountKey, 1);
+        expect(
+            await distributeNative(builtFanout.fanout, member.wallet.publicKey)
+        ).to.be.null;
+        expect(
+            await distributeNative(builtFanout.fanout, other.wallet.publicKey)
+        ).to.be.null;
+        expect(await connection.getBalance(member.wallet.publicKey)).to.equal(
+            0.5 * LAMPORTS_PER_SOL
+        );
+        // Released shares are spread over the remaining members
+        expect(await connection.getBalance(other.wallet.publicKey)).to.equal(
+            1.5 * LAMPORTS_PER_SOL
+        );
+        const fanoutAccount = await Fanout.fromAccountAddress(
+            connection,
+            builtFanout.fanout
+        );
+        const voucher = await FanoutMembershipVoucher.fromAccountAddress(
+            connection,
+            member.voucher
+        );
+        expect(voucher.shares.toString()).to.equal("0");
+        expect(fanoutAccount.totalAvailableShares.toString()).to.equal("50");
+    });
+
+    it("Switches scheduled shares once they take effect", async () => {
+        const builtFanout = await builtWalletFanout(fanoutSdk, 100, 2);
+        const member = builtFanout.members[0];
+        const effectiveAt = (await chainTime()) + 2;
+        expect(
+            await sendAsAuthority(
+                createProcessScheduleShareChangeInstruction(
+                    {
+                        authority: authorityWallet.publicKey,
+                        fanout: builtFanout.fanout,
+                        membershipVoucher: member.voucher,
+                    },
+                    {shares: 30, effectiveAt}
+                )
+            )
+        ).to.be.null;
+        await waitUntil(effectiveAt);
+        // Inflow observed up to the switch is settled at the old shares
+        await airdrop(connection, builtFanout.fanoutAccountData.accountKey, 1);
+        expect(
+            await distributeNative(builtFanout.fanout, member.wallet.publicKey)
+        ).to.be.null;
+        expect(await connection.getBalance(member.wallet.publicKey)).to.equal(
+            0.5 * LAMPORTS_PER_SOL
+        );
+        await airdrop(connection, builtFanout.fanoutAccountData.accountKey, 1);
+        expect(
+            await distributeNative(builtFanout.fanout, member.wallet.publicKey)
+        ).to.be.null;
+        expect(await connection.getBalance(member.wallet.publicKey)).to.equal(
+            0.8 * LAMPORTS_PER_SOL
+        );
+        const fanoutAccount = await Fanout.fromAccountAddress(
+            connection,
+            builtFanout.fanout
+        );
+        const voucher = await FanoutMembershipVoucher.fromAccountAddress(
+            connection,
+            member.voucher
+        );
+        expect(voucher.shares.toString()).to.equal("30");
+        expect(fanoutAccount.totalAvailableShares.toString()).to.equal("20");
+    });
+
+    it("Splits a mint by its own share table", async () => {
+        const builtFanout = await builtWalletFanout(fanoutSdk, 100, 2);
+        const member = builtFanout.members[0];
+        const mint = await Token.createMint(
+            connection,
+            authorityWallet,
+            authorityWallet.publicKey,
+            null,
+            6,
+            TOKEN_PROGRAM_ID
+        );
+        const {fanoutForMint, tokenAccount} =
+            await fanoutSdk.initializeFanoutForMint({
+                fanout: builtFanout.fanout,
+                mint: mint.publicKey,
+            });
+        expect(
+            await sendAsAuthority(
+                createProcessSetMintShareTableInstruction(
+                    {
+                        authority: authorityWallet.publicKey,
+                        fanout: builtFanout.fanout,
+                        fanoutForMint,
+                    },
+                    {totalShares: 10}
+                )
+            )
+        ).to.be.null;
+        const [mintVoucher] = await FanoutClient.mintMembershipVoucher(
+            fanoutForMint,
+            member.wallet.publicKey,
+            mint.publicKey
+        );
+        expect(
+            await sendAsAuthority(
+                createProcessSetMintMemberSharesInstruction(
+                    {
+                        authority: authorityWallet.publicKey,
+                        fanout: builtFanout.fanout,
+                        fanoutForMint,
+                        holdingAccount: tokenAccount,
+                        membershipVoucher: member.voucher,
+                        fanoutForMintMembershipVoucher: mintVoucher,
+                    },
+                    {shares: 4}
+                )
+            )
+        ).to.be.null;
+        await mint.mintTo(tokenAccount, authorityWallet, [], 1000);
+        const dist = await fanoutSdk.distributeWalletMemberInstructions({
+            distributeForMint: true,
+            member: member.wallet.publicKey,
+            fanout: builtFanout.fanout,
+            payer: authorityWallet.publicKey,
+            fanoutMint: mint.publicKey,
+        });
+        expect(
+            await sendOk(
+                fanoutSdk,
+                dist.instructions,
+                [authorityWallet],
+                authorityWallet.publicKey
+            )
+        ).to.be.null;
+        const memberTokenAccount = await Token.getAssociatedTokenAddress(
+            ASSOCIATED_TOKEN_PROGRAM_ID,
+            TOKEN_PROGRAM_ID,
+            mint.publicKey,
+            member.wallet.publicKey
+        );
+        const memberBalance = await connection.getTokenAccountBalance(
+            memberTokenAccount
+        );
+        expect(memberBalance.value.amount).to.equal("400");
+        // Inflow of the unallocated shares goes to the treasury
+        const fanoutMint = await FanoutMint.fromAccountAddress(
+            connection,
+            fanoutForMint
+        );
+        expect(fanoutMint.treasuryUnclaimed.toString()).to.equal("600");
+    });
+
+    it("Lets holders of a verified collection NFT join", async () => {
+        const init = await fanoutSdk.initializeFanout({
+            totalShares: 100,
+            name: `Test${Date.now()}`,
+            membershipModel: MembershipModel.NFT,
+        });
+        const metadataData = (collection: Collection | null) =>
+            new DataV2({
+                uri: "URI",
+                name: "NAME",
+                symbol: "SYMBOL",
+                sellerFeeBasisPoints: 1000,
+                creators: null,
+                collection,
+                uses: null,
+            });
+        const collection = await createMasterEdition(
+            connection,
+            authorityWallet,
+            metadataData(null),
+            0
+        );
+        expect(
+            await sendAsAuthority(
+                createProcessSetMembershipCollectionInstruction(
+                    {
+                        authority: authorityWallet.publicKey,
+                        fanout: init.fanout,
+                        collection: collection.mint.publicKey,
+                    },
+                    {sharesPerNft: 25}
+                )
+            )
+        ).to.be.null;
+        const nft = await createMasterEdition(
+            connection,
+            authorityWallet,
+            metadataData(
+                new Collection({
+                    key: collection.mint.publicKey.toBase58(),
+                    verified: false,
+                })
+            ),
+            0
+        );
+        const holder = Keypair.generate();
+        await airdrop(connection, holder.publicKey, 1);
+        const holderAccount = await nft.mint.getOrCreateAssociatedAccountInfo(
+            holder.publicKey
+        );
+        const authorityAccount = await nft.mint.getOrCreateAssociatedAccountInfo(
+            authorityWallet.publicKey
+        );
+        await nft.mint.transfer(
+            authorityAccount.address,
+            holderAccount.address,
+            authorityWallet,
+            [],
+            1
+        );
+        const [membershipAccount] = await FanoutClient.membershipVoucher(
+            init.fanout,
+            nft.mint.publicKey
+        );
+        const join = () =>
+            sendOk(
+                fanoutSdk,
+                [
+                    createProcessAddMemberNftCollectionInstruction(
+                        {
+                            holder: holder.publicKey,
+                            fanout: init.fanout,
+                            membershipAccount,
+                            mint: nft.mint.publicKey,
+                            membershipMintTokenAccount: holderAccount.address,
+                            metadata: nft.metadata,
+                        },
+                        {args: {shares: 0, proof: []}}
+                    ),
+                ],
+                [holder],
+                holder.publicKey
+            );
+        // Only NFTs verified as part of the collection can join
+        expect(await join()).to.not.be.null;
+        await verifyCollection(
+            connection,
+            authorityWallet,
+            nft.metadata,
+            collection.mint.publicKey
+        );
+        expect(await join()).to.be.null;
+        const voucher = await FanoutMembershipVoucher.fromAccountAddress(
+            connection,
+            membershipAccount
+        );
+        expect(voucher.membershipKey.toBase58()).to.equal(
+            nft.mint.publicKey.toBase58()
+        );
+        expect(voucher.shares.toString()).to.equal("25");
+        const fanoutAccount = await Fanout.fromAccountAddress(
+            connection,
+            init.fanout
+        );
+        expect(fanoutAccount.totalMembers.toString()).to.equal("1");
+        expect(fanoutAccount.totalAvailableShares.toString()).to.equal("75");
+        // Each NFT joins once
+        expect(await join()).to.not.be.null;
+    });
+
+    it("Tracks what members were paid from a mint", async () => {
+        const builtFanout = await builtWalletFanout(fanoutSdk, 100, 2);
+        const member = builtFanout.members[0];
+        const mint = await Token.createMint(
+            connection,
+            authorityWallet,
+            authorityWallet.publicKey,
+            null,
+            6,
+            TOKEN_PROGRAM_ID
+        );
+        const {fanoutForMint, tokenAccount} =
+            await fanoutSdk.initializeFanoutForMint({
+                fanout: builtFanout.fanout,
+                mint: mint.publicKey,
+            });
+        await mint.mintTo(tokenAccount, authorityWallet, [], 1000);
+        const dist = await fanoutSdk.distributeWalletMemberInstructions({
+            distributeForMint: true,
+            member: member.wallet.publicKey,
+            fanout: builtFanout.fanout,
+            payer: authorityWallet.publicKey,
+            fanoutMint: mint.publicKey,
+        });
+        expect(
+            await sendOk(
+                fanoutSdk,
+                dist.instructions,
+                [authorityWallet],
+                authorityWallet.publicKey
+            )
+        ).to.be.null;
+        const [mintVoucher] = await FanoutClient.mintMembershipVoucher(
+            fanoutForMint,
+            member.wallet.publicKey,
+            mint.publicKey
+        );
+        let voucher = await FanoutMembershipMintVoucher.fromAccountAddress(
+            connection,
+            mintVoucher
+        );
+        expect(voucher.totalInflow.toString()).to.equal("500");
+        expect(voucher.lastInflow.toString()).to.equal("1000");
+        const backfill = (totalInflow: number) =>
+            sendAsAuthority(
+                createProcessMigrateMintVoucherInstruction(
+                    {
+                        authority: authorityWallet.publicKey,
+                        fanout: builtFanout.fanout,
+                        fanoutForMint,
+                        membershipKey: member.wallet.publicKey,
+                        fanoutForMintMembershipVoucher: mintVoucher,
+                    },
+                    {totalInflow}
+                )
+            );
+        // Members can't have been paid more than the inflow they settled against
+        expect(await backfill(600)).to.not.be.null;
+        expect(await backfill(300)).to.be.null;
+        voucher = await FanoutMembershipMintVoucher.fromAccountAddress(
+            connection,
+            mintVoucher
+        );
+        expect(voucher.totalInflow.toString()).to.equal("800");
+        expect(voucher.totalInflowMigrated).to.be.true;
+        // Each voucher is backfilled once
+        expect(await backfill(100)).to.not.be.null;
+    });
+
+    it("Stores the fanout metadata", async () => {
+        const builtFanout = await builtWalletFanout(fanoutSdk, 100, 2);
+        const [fanoutMetadata] = await FanoutClient.fanoutMetadata(
+            builtFanout.fanout
+        );
+        const setMetadata = (uri: string, description: string) =>
+            sendAsAuthority(
+                createProcessSetFanoutMetadataInstruction(
+                    {
+                        authority: authorityWallet.publicKey,
+                        fanout: builtFanout.fanout,
+                        fanoutMetadata,
+                    },
+                    {uri, description}
+                )
+            );
+        expect(
+            await setMetadata("https://example.com/fanout.json", "Royalties")
+        ).to.be.null;
+        // Updates rewrite the existing account
+        expect(
+            await setMetadata("https://example.com/v2.json", "Royalty split")
+        ).to.be.null;
+        const metadata = await FanoutMetadata.fromAccountAddress(
+            connection,
+            fanoutMetadata
+        );
+        expect(metadata.fanout.toBase58()).to.equal(builtFanout.fanout.toBase58());
+        expect(metadata.uri).to.equal("https://example.com/v2.json");
+        expect(metadata.description).to.equal("Royalty split");
+        expect(await setMetadata("x".repeat(201), "")).to.not.be.null;
+    });
+
+    it("Labels member vouchers", async () => {
+        const builtFanout = await builtWalletFanout(fanoutSdk, 100, 2);
+        const member = builtFanout.members[0];
+        const setLabel = (label: string | null) =>
+            sendAsAuthority(
+                createProcessSetMemberLabelInstruction(
+                    {
+                        authority: authorityWallet.publicKey,
+                        fanout: builtFanout.fanout,
+                        membershipVoucher: member.voucher,
+                    },
+                    {label}
+                )
+            );
+        expect(await setLabel("Producer")).to.be.null;
+        let voucher = await FanoutMembershipVoucher.fromAccountAddress(
+            connection,
+            member.voucher
+        );
+        expect(voucher.label).to.equal("Producer");
+        expect(await setLabel("x".repeat(33))).to.not.be.null;
+        expect(await setLabel(null)).to.be.null;
+        voucher = await FanoutMembershipVoucher.fromAccountAddress(
+            connection,
+            member.voucher
+        );
+        expect(voucher.label).to.be.null;
+    });
+
+    it("Stakes additional membership mints by their weight", async () => {
+        const [membershipMint, weightedMint] = await Promise.all(
+            [0, 1].map(() =>
+                Token.createMint(
+                    connection,
+                    authorityWallet,
+                    authorityWallet.publicKey,
+                    null,
+                    0,
+                    TOKEN_PROGRAM_ID
+                )
+            )
+        );
+        const {fanout} = await fanoutSdk.initializeFanout({
+            totalShares: 0,
+            name: `Test${Date.now()}`,
+            membershipModel: MembershipModel.Token,
+            mint: membershipMint.publicKey,
+        });
+        const [fanoutMembershipMint] = await PublicKey.findProgramAddress(
+            [
+                Buffer.from("fanout-membership-mint"),
+                fanout.toBuffer(),
+                weightedMint.publicKey.toBuffer(),
+            ],
+            FanoutClient.ID
+        );
+        expect(
+            await sendAsAuthority(
+                createProcessInitForMembershipMintInstruction(
+                    {
+                        authority: authorityWallet.publicKey,
+                        fanout,
+                        fanoutMembershipMint,
+                        membershipMint: weightedMint.publicKey,
+                    },
+                    {weight: 3}
+                )
+            )
+        ).to.be.null;
+        const member = Keypair.generate();
+        await airdrop(connection, member.publicKey, 1);
+        const memberTokenAccount =
+            await weightedMint.createAssociatedTokenAccount(member.publicKey);
+        await weightedMint.mintTo(memberTokenAccount, authorityWallet, [], 10);
+        // Unstaking settles the member through the primary membership mint
+        await membershipMint.createAssociatedTokenAccount(member.publicKey);
+        const [voucher] = await FanoutClient.membershipVoucher(
+            fanout,
+            member.publicKey
+        );
+        const [membershipStake] = await PublicKey.findProgramAddress(
+            [
+                Buffer.from("fanout-membership-stake"),
+                fanoutMembershipMint.toBuffer(),
+                member.publicKey.toBuffer(),
+            ],
+            FanoutClient.ID
+        );
+        const stakeAccount = await Token.getAssociatedTokenAddress(
+            ASSOCIATED_TOKEN_PROGRAM_ID,
+            TOKEN_PROGRAM_ID,
+            weightedMint.publicKey,
+            voucher,
+            true
+        );
+        const weightedAccounts = {
+            member: member.publicKey,
+            fanout,
+            membershipVoucher: voucher,
+            fanoutMembershipMint,
+            membershipMint: weightedMint.publicKey,
+            membershipStake,
+            membershipMintTokenAccount: memberTokenAccount,
+            memberStakeAccount: stakeAccount,
+            instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        };
+        expect(
+            await sendOk(
+                fanoutSdk,
+                [
+                    Token.createAssociatedTokenAccountInstruction(
+                        ASSOCIATED_TOKEN_PROGRAM_ID,
+                        TOKEN_PROGRAM_ID,
+                        weightedMint.publicKey,
+                        stakeAccount,
+                        voucher,
+                        authorityWallet.publicKey
+                    ),
+                    createProcessSetWeightedTokenMemberStakeInstruction(
+                        weightedAccounts,
+                        {shares: 10}
+                    ),
+                ],
+                [authorityWallet, member],
+                authorityWallet.publicKey
+            )
+        ).to.be.null;
+        const stake = await FanoutMembershipStake.fromAccountAddress(
+            connection,
+            membershipStake
+        );
+        expect(stake.membershipMint.toBase58()).to.equal(
+            weightedMint.publicKey.toBase58()
+        );
+        expect(stake.amount.toString()).to.equal("10");
+        let voucherAccount = await FanoutMembershipVoucher.fromAccountAddress(
+            connection,
+            voucher
+        );
+        expect(voucherAccount.shares.toString()).to.equal("30");
+        let fanoutAccount = await Fanout.fromAccountAddress(connection, fanout);
+        expect(fanoutAccount.totalStakedShares?.toString()).to.equal("30");
+        expect(fanoutAccount.weightedMintSupply.toString()).to.equal("30");
+        expect(fanoutAccount.totalShares.toString()).to.equal("30");
+        let membershipMintAccount = await FanoutMembershipMint.fromAccountAddress(
+            connection,
+            fanoutMembershipMint
+        );
+        expect(membershipMintAccount.totalStaked.toString()).to.equal("10");
+
+        const dist = await fanoutSdk.distributeTokenMemberInstructions({
+            distributeForMint: false,
+            membershipMint: membershipMint.publicKey,
+            fanout,
+            member: member.publicKey,
+            payer: authorityWallet.publicKey,
+        });
+        const unstake = createProcessUnstakeWeightedInstruction(weightedAccounts);
+        // The member has to be settled in the same transaction
+        expect(
+            await sendOk(
+                fanoutSdk,
+                [unstake],
+                [authorityWallet, member],
+                authorityWallet.publicKey
+            )
+        ).to.not.be.null;
+        expect(
+            await sendOk(
+                fanoutSdk,
+                [...dist.instructions, unstake],
+                [authorityWallet, member],
+                authorityWallet.publicKey
+            )
+        ).to.be.null;
+        const memberBalance = await connection.getTokenAccountBalance(
+            memberTokenAccount
+        );
+        expect(memberBalance.value.amount).to.equal("10");
+        // The stake and, with nothing else staked, the voucher are closed
+        expect(await connection.getAccountInfo(membershipStake)).to.be.null;
+        expect(await connection.getAccountInfo(voucher)).to.be.null;
+        fanoutAccount = await Fanout.fromAccountAddress(connection, fanout);
+        expect(fanoutAccount.totalStakedShares?.toString()).to.equal("0");
+        membershipMintAccount = await FanoutMembershipMint.fromAccountAddress(
+            connection,
+            fanoutMembershipMint
+        );
+        expect(membershipMintAccount.totalStaked.toString()).to.equal("0");
+    });
+});
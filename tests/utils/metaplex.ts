@@ -7,6 +7,7 @@ import {
   MasterEdition,
   Metadata,
   MetadataDataData,
+  VerifyCollection,
 } from "@metaplex-foundation/mpl-token-metadata";
 import BN from "bn.js";
 import * as spl from "@solana/spl-token";
@@ -133,3 +134,32 @@ export async function createMasterEdition(
   await connection.confirmTransaction(createTxDetails, connection.commitment);
   return { mint, metadata, masterEditionPubkey, createTxDetails };
 }
+
+// -----------------
+// Verify A Collection
+// -----------------
+export async function verifyCollection(
+  connection: Connection,
+  payer: Keypair,
+  metadata: PublicKey,
+  collectionMint: PublicKey
+) {
+  const verifyTx = new VerifyCollection(
+    { feePayer: payer.publicKey },
+    {
+      metadata,
+      collectionAuthority: payer.publicKey,
+      collectionMint,
+      collectionMetadata: await Metadata.getPDA(collectionMint),
+      collectionMasterEdition: await MasterEdition.getPDA(collectionMint),
+    }
+  );
+  verifyTx.recentBlockhash = (await connection.getRecentBlockhash()).blockhash;
+  await verifyTx.sign(payer);
+
+  const verifyTxDetails = await connection.sendRawTransaction(
+    verifyTx.serialize()
+  );
+  await connection.confirmTransaction(verifyTxDetails, connection.commitment);
+  return verifyTxDetails;
+}
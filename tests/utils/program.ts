@@ -0,0 +1,35 @@
+import { Keypair, PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { FanoutClient } from "../../packages/sdk/src";
+
+// Optional accounts, like the fee recipient or a payout destination, are passed after the
+// instruction's own accounts.
+export function withRemainingAccounts(
+  instructions: TransactionInstruction[],
+  accounts: PublicKey[]
+): TransactionInstruction[] {
+  const last = instructions[instructions.length - 1];
+  last.keys.push(
+    ...accounts.map((pubkey) => ({
+      pubkey,
+      isSigner: false,
+      isWritable: true,
+    }))
+  );
+  return instructions;
+}
+
+export async function sendOk(
+  fanoutSdk: FanoutClient,
+  instructions: TransactionInstruction[],
+  signers: Keypair[],
+  payer: PublicKey
+) {
+  const tx = await fanoutSdk.sendInstructions(instructions, signers, payer);
+  if (!!tx.RpcResponseAndContext.value.err) {
+    const txdetails = await fanoutSdk.connection.getConfirmedTransaction(
+      tx.TransactionSignature
+    );
+    console.log(txdetails, tx.RpcResponseAndContext.value.err);
+  }
+  return tx.RpcResponseAndContext.value.err;
+}